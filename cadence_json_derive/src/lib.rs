@@ -2,51 +2,82 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, parse_macro_input,};
+use syn::{Data, DataEnum, DeriveInput, Fields, parse_macro_input,};
 
-#[proc_macro_derive(ToCadenceValue)]
+// Composite kinds that share the CompositeValue representation
+const COMPOSITE_KINDS: &[&str] = &["Struct", "Resource", "Event", "Contract", "Enum"];
+
+#[proc_macro_derive(ToCadenceValue, attributes(cadence))]
 pub fn derive_to_cadence_value(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    expand_to_cadence_value(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_to_cadence_value(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
+    let attrs = ContainerAttrs::parse(input)?;
+    let generics = bounded_generics(input, quote! { serde_cadence::ToCadenceValue });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    if let Some(member) = transparent_member(input, &attrs)? {
+        return Ok(quote! {
+            impl #impl_generics serde_cadence::ToCadenceValue for #name #ty_generics #where_clause {
+                fn to_cadence_value(&self) -> serde_cadence::Result<serde_cadence::CadenceValue> {
+                    serde_cadence::ToCadenceValue::to_cadence_value(&self.#member)
+                }
+            }
+        });
+    }
+    let kind = attrs.kind.as_deref().unwrap_or("Struct");
+    let kind = syn::Ident::new(kind, proc_macro2::Span::call_site());
 
     // Get field information
     let fields = match &input.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(fields) => &fields.named,
-            _ => panic!("ToCadenceValue can only be derived for structs with named fields"),
-        },
-        _ => panic!("ToCadenceValue can only be derived for structs"),
+        Data::Struct(data) => named_fields(&data.fields, "ToCadenceValue")?,
+        Data::Enum(data) if attrs.string_repr => {
+            return enum_to_cadence_string(name, &generics, data);
+        }
+        Data::Enum(data) => return enum_to_cadence_value(name, &generics, data),
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "ToCadenceValue can only be derived for structs and enums",
+            ));
+        }
     };
 
-    // Generate code for each field
-    let field_conversions = fields.iter().map(|field| {
-        let field_name = &field.ident;
-        let field_name_str = field_name.as_ref().unwrap().to_string();
-
-        // Look for serde rename attribute
-        let renamed = find_serde_rename(field);
-        let field_name_for_cadence = renamed.unwrap_or_else(|| field_name_str.clone());
+    // Generate code for each field; `PhantomData` markers carry no data and
+    // are left out
+    let field_conversions = fields
+        .iter()
+        .filter(|field| !is_phantom_data_type(&field.ty))
+        .map(|field| {
+            let field_name = &field.ident;
+            let field_attrs = FieldAttrs::parse(field)?;
+            let field_name_for_cadence = cadence_field_name(field, &field_attrs, 0);
 
-        quote! {
-            let #field_name = serde_cadence::CompositeField {
-                name: #field_name_for_cadence.to_string(),
-                value: self.#field_name.to_cadence_value()?,
-            };
-            fields.push(#field_name);
-        }
-    });
+            Ok(quote! {
+                let #field_name = serde_cadence::CompositeField {
+                    name: ::core::convert::Into::into(#field_name_for_cadence),
+                    value: serde_cadence::ToCadenceValue::to_cadence_value(&self.#field_name)?,
+                };
+                fields.push(#field_name);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
     // Generate the impl
     let expanded = quote! {
-        impl serde_cadence::ToCadenceValue for #name {
+        impl #impl_generics serde_cadence::ToCadenceValue for #name #ty_generics #where_clause {
             fn to_cadence_value(&self) -> serde_cadence::Result<serde_cadence::CadenceValue> {
-                let mut fields = Vec::new();
+                let mut fields = serde_cadence::__private::Vec::new();
 
                 #(#field_conversions)*
 
-                Ok(serde_cadence::CadenceValue::Struct {
+                Ok(serde_cadence::CadenceValue::#kind {
                     value: serde_cadence::CompositeValue {
-                        id: stringify!(#name).to_string(),
+                        id: ::core::convert::Into::into(stringify!(#name)),
                         fields,
                     },
                 })
@@ -54,45 +85,83 @@ pub fn derive_to_cadence_value(input: TokenStream) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
 
-#[proc_macro_derive(FromCadenceValue)]
+#[proc_macro_derive(FromCadenceValue, attributes(cadence))]
 pub fn derive_from_cadence_value(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    expand_from_cadence_value(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_cadence_value(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
+    let attrs = ContainerAttrs::parse(input)?;
+    let generics = bounded_generics(input, quote! { serde_cadence::FromCadenceValue });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    if let Some(member) = transparent_member(input, &attrs)? {
+        return Ok(quote! {
+            impl #impl_generics serde_cadence::FromCadenceValue for #name #ty_generics #where_clause {
+                fn from_cadence_value(value: &serde_cadence::CadenceValue) -> serde_cadence::Result<Self> {
+                    Ok(Self {
+                        #member: serde_cadence::FromCadenceValue::from_cadence_value(value)?,
+                    })
+                }
+            }
+        });
+    }
+
+    // Accept every composite kind unless the type pins one down
+    let mut accepted_kinds: Vec<&str> = match &attrs.kind {
+        Some(kind) => vec![kind.as_str()],
+        None => COMPOSITE_KINDS.to_vec(),
+    };
+    let kind_patterns: Vec<_> = accepted_kinds
+        .iter()
+        .map(|kind| {
+            let kind = syn::Ident::new(kind, proc_macro2::Span::call_site());
+            quote! { serde_cadence::CadenceValue::#kind { value: composite } }
+        })
+        .collect();
 
     // Get field information
     let fields = match &input.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(fields) => &fields.named,
-            _ => panic!("FromCadenceValue can only be derived for structs with named fields"),
-        },
-        _ => panic!("FromCadenceValue can only be derived for structs"),
+        Data::Struct(data) => named_fields(&data.fields, "FromCadenceValue")?,
+        Data::Enum(data) if attrs.string_repr => {
+            return enum_from_cadence_string(name, &generics, data);
+        }
+        Data::Enum(data) => return enum_from_cadence_value(name, &generics, data),
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "FromCadenceValue can only be derived for structs and enums",
+            ));
+        }
     };
 
-    // Generate field extraction code
-    let field_extractions = fields.iter().map(|field| {
-        let field_name = &field.ident;
-        let field_name_str = field_name.as_ref().unwrap().to_string();
-
-        // Look for serde rename attribute
-        let renamed = find_serde_rename(field);
-        let field_name_for_cadence = renamed.unwrap_or_else(|| field_name_str.clone());
+    // A struct without fields, unit structs included, carries no data, so it
+    // also decodes from `Void`
+    let void_arm = if fields.is_empty() {
+        accepted_kinds.push("Void");
+        quote! { serde_cadence::CadenceValue::Void { .. } => Ok(Self {}), }
+    } else {
+        quote! {}
+    };
+    let expected_kinds = accepted_kinds.join(" or ");
 
-        quote! {
-            let #field_name = {
-                let field = fields.iter()
-                    .find(|f| f.name == #field_name_for_cadence)
-                    .ok_or_else(||
-                        serde_cadence::Error::Custom(
-                            format!("Field {} not found in Cadence value", #field_name_for_cadence)
-                        )
-                    )?;
-                serde_cadence::FromCadenceValue::from_cadence_value(&field.value)?
-            };
-        }
-    });
+    // Generate field extraction code
+    let field_extractions = fields
+        .iter()
+        .enumerate()
+        .map(|(position, field)| {
+            let field_name = field.ident.as_ref().unwrap();
+            let field_attrs = FieldAttrs::parse(field)?;
+            let field_name_for_cadence = cadence_field_name(field, &field_attrs, position);
+            Ok(field_extraction(field_name, &field_name_for_cadence, field, &field_attrs))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
     // Generate struct construction
     let field_names = fields.iter().map(|field| {
@@ -102,10 +171,10 @@ pub fn derive_from_cadence_value(input: TokenStream) -> TokenStream {
 
     // Generate the impl
     let expanded = quote! {
-        impl serde_cadence::FromCadenceValue for #name {
+        impl #impl_generics serde_cadence::FromCadenceValue for #name #ty_generics #where_clause {
             fn from_cadence_value(value: &serde_cadence::CadenceValue) -> serde_cadence::Result<Self> {
                 match value {
-                    serde_cadence::CadenceValue::Struct { value: composite } => {
+                    #(#kind_patterns)|* => {
                         let fields = &composite.fields;
 
                         #(#field_extractions)*
@@ -114,39 +183,568 @@ pub fn derive_from_cadence_value(input: TokenStream) -> TokenStream {
                             #(#field_names),*
                         })
                     },
+                    #void_arm
                     _ => Err(serde_cadence::Error::TypeMismatch {
-                        expected: "Struct".to_string(),
-                        got: format!("{:?}", value),
+                        expected: ::core::convert::Into::into(#expected_kinds),
+                        got: serde_cadence::__private::format!("{:?}", value),
                     }),
                 }
             }
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
+}
+
+// The named fields of a struct, none for a unit struct, or an error pointing
+// at the fields of a tuple struct
+fn named_fields<'a>(fields: &'a Fields, derive: &str) -> syn::Result<Vec<&'a syn::Field>> {
+    match fields {
+        Fields::Named(fields) => Ok(fields.named.iter().collect()),
+        Fields::Unit => Ok(Vec::new()),
+        Fields::Unnamed(fields) => Err(syn::Error::new_spanned(
+            fields,
+            format!("{} can only be derived for structs with named fields", derive),
+        )),
+    }
+}
+
+// Enums map to a Cadence `Enum` composite whose `rawValue` field holds the
+// variant index as a UInt8. Payload fields follow it, named after the Rust
+// fields for struct-like variants and `0`, `1`, ... for tuple-like variants.
+fn enum_to_cadence_value(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    data: &DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    check_variant_count(name, data)?;
+
+    let mut arms = Vec::new();
+    for (index, variant) in data.variants.iter().enumerate() {
+        let variant_name = &variant.ident;
+        let raw_value = index as u8;
+        let bindings = variant_bindings(&variant.fields);
+        let payload = variant
+            .fields
+            .iter()
+            .zip(&bindings)
+            .enumerate()
+            .map(|(position, (field, binding))| {
+                let field_name = cadence_field_name(field, &FieldAttrs::parse(field)?, position);
+                Ok(quote! {
+                    serde_cadence::CompositeField {
+                        name: ::core::convert::Into::into(#field_name),
+                        value: serde_cadence::ToCadenceValue::to_cadence_value(#binding)?,
+                    }
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote! { Self::#variant_name { #(#bindings),* } },
+            Fields::Unnamed(_) => quote! { Self::#variant_name ( #(#bindings),* ) },
+            Fields::Unit => quote! { Self::#variant_name },
+        };
+        arms.push(quote! {
+            #pattern => (#raw_value, serde_cadence::__private::vec![#(#payload),*]),
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics serde_cadence::ToCadenceValue for #name #ty_generics #where_clause {
+            fn to_cadence_value(&self) -> serde_cadence::Result<serde_cadence::CadenceValue> {
+                let (raw_value, payload): (u8, serde_cadence::__private::Vec<serde_cadence::CompositeField>) = match self {
+                    #(#arms)*
+                };
+
+                let mut fields = serde_cadence::__private::vec![serde_cadence::CompositeField {
+                    name: ::core::convert::Into::into("rawValue"),
+                    value: serde_cadence::CadenceValue::UInt8 {
+                        value: serde_cadence::__private::ToString::to_string(&raw_value),
+                    },
+                }];
+                fields.extend(payload);
+
+                Ok(serde_cadence::CadenceValue::Enum {
+                    value: serde_cadence::CompositeValue {
+                        id: ::core::convert::Into::into(stringify!(#name)),
+                        fields,
+                    },
+                })
+            }
+        }
+    })
 }
 
-// Helper function to extract the rename value from serde attributes
-fn find_serde_rename(field: &syn::Field) -> Option<String> {
-    for attr in &field.attrs {
-        if attr.path().is_ident("serde") {
-            // Use parse_nested_meta instead of parse_meta
-            let mut rename_value = None;
+fn enum_from_cadence_value(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    data: &DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    check_variant_count(name, data)?;
+
+    let mut arms = Vec::new();
+    for (index, variant) in data.variants.iter().enumerate() {
+        let variant_name = &variant.ident;
+        let raw_value = index as u8;
+        let bindings = variant_bindings(&variant.fields);
+        let extractions = variant
+            .fields
+            .iter()
+            .zip(&bindings)
+            .enumerate()
+            .map(|(position, (field, binding))| {
+                let field_attrs = FieldAttrs::parse(field)?;
+                let field_name = cadence_field_name(field, &field_attrs, position);
+                Ok(field_extraction(binding, &field_name, field, &field_attrs))
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        let construction = match &variant.fields {
+            Fields::Named(_) => quote! { Self::#variant_name { #(#bindings),* } },
+            Fields::Unnamed(_) => quote! { Self::#variant_name ( #(#bindings),* ) },
+            Fields::Unit => quote! { Self::#variant_name },
+        };
+        arms.push(quote! {
+            #raw_value => {
+                #(#extractions)*
+
+                Ok(#construction)
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics serde_cadence::FromCadenceValue for #name #ty_generics #where_clause {
+            fn from_cadence_value(value: &serde_cadence::CadenceValue) -> serde_cadence::Result<Self> {
+                match value {
+                    serde_cadence::CadenceValue::Enum { value: composite } => {
+                        let fields = &composite.fields;
 
-            // This is the new way to parse attributes in Syn 2.0
-            let _ = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("rename") {
-                    // Parse the string literal value
-                    let value = meta.value()?.parse::<syn::LitStr>()?;
-                    rename_value = Some(value.value());
+                        let raw_value: u8 = match fields.iter().find(|f| f.name == "rawValue") {
+                            Some(field) => serde_cadence::FromCadenceValue::from_cadence_value(&field.value)?,
+                            None => {
+                                return Err(serde_cadence::Error::Custom(
+                                    ::core::convert::Into::into("Field rawValue not found in Cadence value")
+                                ))
+                            }
+                        };
+
+                        match raw_value {
+                            #(#arms)*
+                            other => Err(serde_cadence::Error::Custom(serde_cadence::__private::format!(
+                                "Unknown rawValue {} for enum {}",
+                                other,
+                                stringify!(#name)
+                            ))),
+                        }
+                    },
+                    _ => Err(serde_cadence::Error::TypeMismatch {
+                        expected: ::core::convert::Into::into("Enum"),
+                        got: serde_cadence::__private::format!("{:?}", value),
+                    }),
                 }
-                Ok(())
-            });
+            }
+        }
+    })
+}
+
+// With `#[cadence(repr = "string")]`, fieldless enums are encoded as a
+// `String` holding the variant name instead of an `Enum` composite
+fn enum_to_cadence_string(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    data: &DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    check_fieldless(data)?;
 
-            if rename_value.is_some() {
-                return rename_value;
+    let arms = data.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        quote! {
+            Self::#variant_name => stringify!(#variant_name),
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics serde_cadence::ToCadenceValue for #name #ty_generics #where_clause {
+            fn to_cadence_value(&self) -> serde_cadence::Result<serde_cadence::CadenceValue> {
+                let case: &str = match self {
+                    #(#arms)*
+                };
+                Ok(serde_cadence::CadenceValue::String {
+                    value: serde_cadence::__private::ToString::to_string(case),
+                })
             }
         }
+    })
+}
+
+fn enum_from_cadence_string(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    data: &DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    check_fieldless(data)?;
+
+    let arms = data.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        quote! {
+            stringify!(#variant_name) => Ok(Self::#variant_name),
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics serde_cadence::FromCadenceValue for #name #ty_generics #where_clause {
+            fn from_cadence_value(value: &serde_cadence::CadenceValue) -> serde_cadence::Result<Self> {
+                match value {
+                    serde_cadence::CadenceValue::String { value: case } => match case.as_str() {
+                        #(#arms)*
+                        other => Err(serde_cadence::Error::InvalidCadenceValue(serde_cadence::__private::format!(
+                            "Unknown case {:?} for enum {}",
+                            other,
+                            stringify!(#name)
+                        ))),
+                    },
+                    _ => Err(serde_cadence::Error::TypeMismatch {
+                        expected: ::core::convert::Into::into("String"),
+                        got: serde_cadence::__private::format!("{:?}", value),
+                    }),
+                }
+            }
+        }
+    })
+}
+
+// String-represented enums only carry the variant name
+fn check_fieldless(data: &DataEnum) -> syn::Result<()> {
+    match data.variants.iter().find(|variant| !variant.fields.is_empty()) {
+        Some(variant) => Err(syn::Error::new_spanned(
+            &variant.fields,
+            "#[cadence(repr = \"string\")] requires variants without fields",
+        )),
+        None => Ok(()),
+    }
+}
+
+// The rawValue is a UInt8, which caps the number of variants
+fn check_variant_count(name: &syn::Ident, data: &DataEnum) -> syn::Result<()> {
+    if data.variants.len() > u8::MAX as usize + 1 {
+        return Err(syn::Error::new_spanned(
+            name,
+            "Cadence enums can have at most 256 variants",
+        ));
     }
-    None
-}
\ No newline at end of file
+    Ok(())
+}
+
+// Local names bound to a variant's fields in generated patterns
+fn variant_bindings(fields: &Fields) -> Vec<syn::Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(position, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => quote::format_ident!("__field{}", position),
+        })
+        .collect()
+}
+
+// The Cadence field name, honouring `#[serde(rename)]`; tuple fields are
+// named after their position
+fn cadence_field_name(field: &syn::Field, attrs: &FieldAttrs, position: usize) -> String {
+    attrs.rename.clone().unwrap_or_else(|| match &field.ident {
+        Some(ident) => ident.to_string(),
+        None => position.to_string(),
+    })
+}
+
+// Generates code binding `binding` to the named field of `fields`, falling back
+// to the field's default when it is absent
+fn field_extraction(
+    binding: &syn::Ident,
+    field_name_for_cadence: &str,
+    field: &syn::Field,
+    attrs: &FieldAttrs,
+) -> proc_macro2::TokenStream {
+    // What to do when the field is absent from the incoming value
+    let missing = match &attrs.default {
+        Some(FieldDefault::Default) => quote! { ::core::default::Default::default() },
+        Some(FieldDefault::Function(path)) => quote! { #path() },
+        None if is_option_type(&field.ty) => quote! { ::core::option::Option::None },
+        None if is_phantom_data_type(&field.ty) => quote! { ::core::marker::PhantomData },
+        None => quote! {
+            return Err(serde_cadence::Error::Custom(
+                serde_cadence::__private::format!("Field {} not found in Cadence value", #field_name_for_cadence)
+            ))
+        },
+    };
+
+    quote! {
+        let #binding = match fields.iter().find(|f| f.name == #field_name_for_cadence) {
+            Some(field) => serde_cadence::FromCadenceValue::from_cadence_value(&field.value)
+                .map_err(|e| e.at_field(#field_name_for_cadence))?,
+            None => #missing,
+        };
+    }
+}
+
+// The container attributes from `#[cadence(...)]`, parsed once and checked
+// against each other and against the item they annotate
+struct ContainerAttrs {
+    // `kind = "..."`, one of COMPOSITE_KINDS
+    kind: Option<String>,
+    // `repr = "string"`
+    string_repr: bool,
+    // `transparent`, kept for its span
+    transparent: Option<syn::Path>,
+}
+
+impl ContainerAttrs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut kind: Option<syn::LitStr> = None;
+        let mut repr: Option<syn::LitStr> = None;
+        let mut transparent: Option<syn::Path> = None;
+        for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("cadence")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("kind") {
+                    set_once(&meta, &mut kind, meta.value()?.parse()?)
+                } else if meta.path.is_ident("repr") {
+                    set_once(&meta, &mut repr, meta.value()?.parse()?)
+                } else if meta.path.is_ident("transparent") {
+                    set_once(&meta, &mut transparent, meta.path.clone())
+                } else {
+                    Err(meta.error("unknown cadence attribute"))
+                }
+            })?;
+        }
+
+        if let Some(kind) = &kind
+            && !COMPOSITE_KINDS.contains(&kind.value().as_str())
+        {
+            return Err(syn::Error::new_spanned(
+                kind,
+                format!(
+                    "Unsupported cadence kind `{}`, expected one of {}",
+                    kind.value(),
+                    COMPOSITE_KINDS.join(", ")
+                ),
+            ));
+        }
+        if let Some(repr) = &repr
+            && repr.value() != "string"
+        {
+            return Err(syn::Error::new_spanned(
+                repr,
+                format!("Unsupported cadence repr `{}`, expected `string`", repr.value()),
+            ));
+        }
+        if let Some(path) = &transparent
+            && (kind.is_some() || repr.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                path,
+                "#[cadence(transparent)] cannot be combined with `kind` or `repr`",
+            ));
+        }
+        match &input.data {
+            Data::Enum(_) => {
+                if let Some(kind) = &kind {
+                    return Err(syn::Error::new_spanned(
+                        kind,
+                        "#[cadence(kind)] can only be used on structs, enums are always `Enum`",
+                    ));
+                }
+            }
+            _ => {
+                if let Some(repr) = &repr {
+                    return Err(syn::Error::new_spanned(
+                        repr,
+                        "#[cadence(repr)] can only be used on enums",
+                    ));
+                }
+            }
+        }
+
+        Ok(ContainerAttrs {
+            kind: kind.map(|kind| kind.value()),
+            string_repr: repr.is_some(),
+            transparent,
+        })
+    }
+}
+
+// The field attributes from `#[cadence(...)]`, plus the `#[serde(rename)]`
+// the derives honour
+struct FieldAttrs {
+    rename: Option<String>,
+    default: Option<FieldDefault>,
+}
+
+// How a missing field is filled in, from `#[cadence(default)]` or
+// `#[cadence(default = "path::to::fn")]`
+enum FieldDefault {
+    Default,
+    Function(syn::Path),
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut rename: Option<String> = None;
+        let mut default: Option<FieldDefault> = None;
+        for attr in &field.attrs {
+            if attr.path().is_ident("cadence") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("default") {
+                        let value = if meta.input.peek(syn::Token![=]) {
+                            let path = meta.value()?.parse::<syn::LitStr>()?;
+                            FieldDefault::Function(path.parse()?)
+                        } else {
+                            FieldDefault::Default
+                        };
+                        set_once(&meta, &mut default, value)
+                    } else {
+                        Err(meta.error("unknown cadence attribute"))
+                    }
+                })?;
+            } else if attr.path().is_ident("serde") {
+                // Other serde options are serde's business; skip over them
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") && meta.input.peek(syn::Token![=]) {
+                        rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                    } else if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::Expr>()?;
+                    } else if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|nested| {
+                            if nested.input.peek(syn::Token![=]) {
+                                nested.value()?.parse::<syn::Expr>()?;
+                            }
+                            Ok(())
+                        })?;
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+        Ok(FieldAttrs { rename, default })
+    }
+}
+
+// Stores an attribute value, rejecting a second occurrence of the same key
+fn set_once<T>(
+    meta: &syn::meta::ParseNestedMeta,
+    slot: &mut Option<T>,
+    value: T,
+) -> syn::Result<()> {
+    if slot.is_some() {
+        let key = meta.path.get_ident().map(ToString::to_string).unwrap_or_default();
+        return Err(meta.error(format!("duplicate cadence attribute `{}`", key)));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+// With `#[cadence(transparent)]`, returns the only field of the struct, which
+// is encoded in place of the struct itself
+fn transparent_member(
+    input: &DeriveInput,
+    attrs: &ContainerAttrs,
+) -> syn::Result<Option<syn::Member>> {
+    let Some(path) = &attrs.transparent else {
+        return Ok(None);
+    };
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                path,
+                "#[cadence(transparent)] can only be used on structs",
+            ));
+        }
+    };
+    if fields.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            path,
+            format!(
+                "#[cadence(transparent)] requires a struct with exactly one field, found {}",
+                fields.len()
+            ),
+        ));
+    }
+    let field = fields.iter().next().unwrap();
+    FieldAttrs::parse(field)?;
+    Ok(Some(match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(0)),
+    }))
+}
+
+// The type's generics with `T: <bound>` added for every type parameter used by
+// a converted field, and `T: Sync` for those only used by `PhantomData`
+// markers, since both conversion traits require `Sync`
+fn bounded_generics(input: &DeriveInput, bound: proc_macro2::TokenStream) -> syn::Generics {
+    let fields: Vec<&syn::Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let mut generics = input.generics.clone();
+    let params: Vec<syn::Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        let converted = fields.iter().any(|field| {
+            let ty = &field.ty;
+            !is_phantom_data_type(ty) && mentions_ident(quote! { #ty }, &param)
+        });
+        let predicate = if converted {
+            quote! { #param: #bound }
+        } else {
+            quote! { #param: ::core::marker::Sync }
+        };
+        where_clause.predicates.push(syn::parse_quote! { #predicate });
+    }
+    generics
+}
+
+// Whether `ident` appears anywhere in `tokens`, including nested groups
+fn mentions_ident(tokens: proc_macro2::TokenStream, ident: &syn::Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(found) => found == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+// Helper function to detect `Option<...>` field types syntactically
+fn is_option_type(ty: &syn::Type) -> bool {
+    is_generic_type_named(ty, "Option")
+}
+
+// Helper function to detect `PhantomData<...>` field types syntactically
+fn is_phantom_data_type(ty: &syn::Type) -> bool {
+    is_generic_type_named(ty, "PhantomData")
+}
+
+fn is_generic_type_named(ty: &syn::Type, name: &str) -> bool {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| {
+                segment.ident == name
+                    && matches!(segment.arguments, syn::PathArguments::AngleBracketed(_))
+            }),
+        _ => false,
+    }
+}
@@ -0,0 +1,89 @@
+use serde_cadence::{
+    AccountKey, CadenceValue, Error, FromCadenceValue, HashAlgorithm, SignatureAlgorithm,
+};
+
+fn main() -> Result<(), Error> {
+    // The result of a script such as
+    //
+    //     access(all) fun main(address: Address): AccountKey? {
+    //         return getAccount(address).keys.get(keyIndex: 0)
+    //     }
+    //
+    // with the public key shortened to a few bytes
+    let cadence_json = r#"{
+        "type": "Struct",
+        "value": {
+            "id": "AccountKey",
+            "fields": [
+                {"name": "keyIndex", "value": {"type": "Int", "value": "0"}},
+                {
+                    "name": "publicKey",
+                    "value": {
+                        "type": "Struct",
+                        "value": {
+                            "id": "PublicKey",
+                            "fields": [
+                                {
+                                    "name": "publicKey",
+                                    "value": {
+                                        "type": "Array",
+                                        "value": [
+                                            {"type": "UInt8", "value": "4"},
+                                            {"type": "UInt8", "value": "183"},
+                                            {"type": "UInt8", "value": "42"}
+                                        ]
+                                    }
+                                },
+                                {
+                                    "name": "signatureAlgorithm",
+                                    "value": {
+                                        "type": "Enum",
+                                        "value": {
+                                            "id": "SignatureAlgorithm",
+                                            "fields": [
+                                                {
+                                                    "name": "rawValue",
+                                                    "value": {"type": "UInt8", "value": "1"}
+                                                }
+                                            ]
+                                        }
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                },
+                {
+                    "name": "hashAlgorithm",
+                    "value": {
+                        "type": "Enum",
+                        "value": {
+                            "id": "HashAlgorithm",
+                            "fields": [
+                                {"name": "rawValue", "value": {"type": "UInt8", "value": "3"}}
+                            ]
+                        }
+                    }
+                },
+                {"name": "weight", "value": {"type": "UFix64", "value": "1000.00000000"}},
+                {"name": "isRevoked", "value": {"type": "Bool", "value": false}}
+            ]
+        }
+    }"#;
+
+    let value: CadenceValue = serde_cadence::from_str(cadence_json)?;
+    let key = AccountKey::from_cadence_value(&value)?;
+    println!("Decoded account key: {:#?}", key);
+
+    assert_eq!(key.key_index, 0);
+    assert_eq!(&key.public_key.public_key[..], &[4, 183, 42]);
+    assert_eq!(
+        key.public_key.signature_algorithm,
+        SignatureAlgorithm::EcdsaP256
+    );
+    assert_eq!(key.hash_algorithm, HashAlgorithm::Sha3_256);
+    assert_eq!(key.weight, "1000.0".parse()?);
+    assert!(!key.is_revoked);
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use serde_cadence::{CadenceValue, Error, FromCadenceValue, ToCadenceValue};
+use serde_cadence::{Address, CadenceValue, Error, FromCadenceValue, ToCadenceValue};
 use std::collections::HashMap;
 
 fn main() -> Result<(), Error> {
@@ -43,15 +43,16 @@ fn main() -> Result<(), Error> {
     let cadence_value: CadenceValue = serde_json::from_str(cadence_json)?;
     println!("Original Cadence-JSON Address Dictionary:\n{}\n", cadence_json);
 
-    // Convert CadenceValue to our HashMap<String, String>
-    // We use String keys for addresses since we don't have a dedicated Address type in Rust
-    let address_map = HashMap::<String, String>::from_cadence_value(&cadence_value)?;
-    println!("Deserialized to Rust HashMap<String, String>:\n{:#?}\n", address_map);
+    // Convert CadenceValue to a HashMap keyed by the typed Address
+    let address_map = HashMap::<Address, String>::from_cadence_value(&cadence_value)?;
+    println!("Deserialized to Rust HashMap<Address, String>:\n{:#?}\n", address_map);
+    let alice: Address = "0x1234567890abcdef".parse()?;
+    assert_eq!(address_map[&alice], "Alice's Account");
 
     // Let's modify the map
     let mut modified_map = address_map.clone();
-    modified_map.insert("0xdeadbeef00000000".to_string(), "Dave's Account".to_string());
-    modified_map.remove("0xf1e2d3c4b5a69780");
+    modified_map.insert("0xdeadbeef00000000".parse()?, "Dave's Account".to_string());
+    modified_map.remove(&"0xf1e2d3c4b5a69780".parse()?);
     println!("Modified HashMap:\n{:#?}\n", modified_map);
 
     // Convert back to CadenceValue
@@ -63,7 +64,7 @@ fn main() -> Result<(), Error> {
 
     // Let's test that we can round-trip our data
     let round_trip_value: CadenceValue = serde_json::from_str(&new_json)?;
-    let round_trip_map = HashMap::<String, String>::from_cadence_value(&round_trip_value)?;
+    let round_trip_map = HashMap::<Address, String>::from_cadence_value(&round_trip_value)?;
 
     // Ensure our data made it through correctly
     assert_eq!(
@@ -0,0 +1,86 @@
+// examples/conversion_benchmark.rs
+//
+// Times `cadence_value_to_value` on a very wide event, like the large event
+// batches some scripts return, and on a dictionary of the same size, then
+// times `value_to_cadence_value` on hand-written Cadence-JSON that uses JSON
+// numbers. Run it with `--release`.
+use serde_cadence::{
+    CadenceValue, CompositeField, CompositeValue, DictionaryEntry, Result, cadence_value_to_value,
+    value_to_cadence_value,
+};
+use std::time::Instant;
+
+const WIDTH: usize = 50_000;
+
+fn main() -> Result<()> {
+    let event = CadenceValue::Event {
+        value: CompositeValue {
+            id: "A.0x1.Batch.Transferred".into(),
+            fields: (0..WIDTH)
+                .map(|i| CompositeField {
+                    name: format!("field{}", i).as_str().into(),
+                    value: if i % 2 == 0 {
+                        CadenceValue::UInt64 {
+                            value: i.to_string(),
+                        }
+                    } else {
+                        CadenceValue::String {
+                            value: format!("value {}", i),
+                        }
+                    },
+                })
+                .collect(),
+        },
+    };
+    let dictionary = CadenceValue::Dictionary {
+        value: (0..WIDTH)
+            .map(|i| DictionaryEntry {
+                key: CadenceValue::from(format!("key{}", i)),
+                value: CadenceValue::from(i % 3 == 0),
+            })
+            .collect(),
+    };
+
+    let iterations = 20;
+    for (label, value) in [("composite", &event), ("dictionary", &dictionary)] {
+        let start = Instant::now();
+        let mut json = None;
+        for _ in 0..iterations {
+            json = Some(cadence_value_to_value(value)?);
+        }
+        println!(
+            "{} with {} fields: {:?} per conversion",
+            label,
+            WIDTH,
+            start.elapsed() / iterations
+        );
+
+        let object = json.unwrap();
+        let object = object.as_object().expect("converts to a JSON object");
+        assert_eq!(object.len(), WIDTH);
+    }
+
+    let json = cadence_value_to_value(&event)?;
+    assert_eq!(json["field0"], 0);
+    assert_eq!(json["field49999"], "value 49999");
+
+    // Hand-written input often has `"value": 5` instead of `"value": "5"`
+    let items: Vec<String> = (0..WIDTH)
+        .map(|i| format!(r#"{{"type":"UInt64","value":{}}}"#, i))
+        .collect();
+    let numeric_json = format!(r#"{{"type":"Array","value":[{}]}}"#, items.join(","));
+    let numeric: serde_json::Value = serde_json::from_str(&numeric_json)?;
+    let start = Instant::now();
+    let mut converted = None;
+    for _ in 0..iterations {
+        converted = Some(value_to_cadence_value(&numeric)?);
+    }
+    println!(
+        "numeric Cadence-JSON with {} items: {:?} per conversion",
+        WIDTH,
+        start.elapsed() / iterations
+    );
+    assert!(converted.is_some());
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use serde_cadence::{CadenceValue, Error, FromCadenceValue, ToCadenceValue};
+use serde_cadence::{CadenceValue, Error, FromCadenceValue, Pairs, ToCadenceValue};
 use std::collections::HashMap;
 
 fn main() -> Result<(), Error> {
@@ -6,9 +6,15 @@ fn main() -> Result<(), Error> {
     let cadence_json = r#"{"value":[{"key":{"value":"banana","type":"String"},"value":{"value":"10","type":"Int"}},{"key":{"value":"cherry","type":"String"},"value":{"value":"15","type":"Int"}},{"key":{"value":"apple","type":"String"},"value":{"value":"5","type":"Int"}}],"type":"Dictionary"}"#;
 
     // Parse the JSON string into a CadenceValue
-    let cadence_value: CadenceValue = serde_json::from_str(cadence_json)?;
+    let cadence_value = serde_cadence::from_str_value(cadence_json)?;
+    assert_eq!(cadence_value.as_dictionary().map(<[_]>::len), Some(3));
     println!("Original Cadence-JSON Dictionary:\n{}\n", cadence_json);
 
+    // Look up a single entry without converting the whole dictionary
+    let bananas = cadence_value.dictionary_get(&"banana".into());
+    println!("Bananas: {:?}\n", bananas);
+    assert_eq!(bananas, Some(&CadenceValue::Int { value: "10".to_string() }));
+
     // Convert CadenceValue to our structured FruitInventory
     let fruit_inventory = HashMap::<String, i32>::from_cadence_value(&cadence_value)?;
     println!("Deserialized to Rust struct:\n{:#?}\n", fruit_inventory);
@@ -36,5 +42,31 @@ fn main() -> Result<(), Error> {
     );
     println!("Round-trip test successful!");
 
+    // Non-String keys keep their Cadence type, so integer keys round-trip too
+    let token_names =
+        HashMap::from([(1u64, "Flow".to_string()), (u64::MAX, "Max".to_string())]);
+    let token_value = token_names.to_cadence_value()?;
+    let token_json = serde_json::to_string(&token_value)?;
+    let round_trip_names =
+        HashMap::<u64, String>::from_cadence_value(&serde_json::from_str(&token_json)?)?;
+    assert_eq!(token_names, round_trip_names, "UInt64 keys didn't round-trip");
+    println!("UInt64-keyed dictionary: {}", token_json);
+
+    // `Pairs` keeps entries in order without going through a map type
+    let ranking = Pairs(vec![("cherry".to_string(), 15), ("banana".to_string(), 10)]);
+    let ranking_value = ranking.to_cadence_value()?;
+    assert_eq!(Pairs::<String, i32>::from_cadence_value(&ranking_value)?, ranking);
+    println!("Ordered pairs: {}", serde_json::to_string(&ranking_value)?);
+
+    // Plain JSON objects convert directly, becoming String-keyed dictionaries
+    let settings = serde_json::json!({
+        "theme": "dark",
+        "limits": {"daily": 5, "tags": ["a", "b"]},
+        "beta": true
+    });
+    let settings_value = settings.to_cadence_value()?;
+    assert_eq!(serde_json::Value::from_cadence_value(&settings_value)?, settings);
+    println!("JSON object as Cadence: {}", serde_json::to_string(&settings_value)?);
+
     Ok(())
 }
@@ -0,0 +1,52 @@
+// examples/fuzz_roundtrip.rs
+//
+// A fuzz-style harness: turns byte buffers into arbitrary CadenceValues,
+// serializes them to Cadence-JSON and parses them back. The same body can be
+// used as a cargo-fuzz target by feeding it the fuzzer's input instead.
+use arbitrary::{Arbitrary, Unstructured};
+use serde_cadence::{CadenceValue, ParseOptions, Result};
+
+fn roundtrip(data: &[u8]) -> Result<()> {
+    let mut u = Unstructured::new(data);
+    let Ok(value) = CadenceValue::arbitrary(&mut u) else {
+        return Ok(());
+    };
+
+    let json = serde_json::to_string(&value)?;
+    let parsed: CadenceValue = serde_json::from_str(&json)?;
+    assert_eq!(value, parsed, "Round-trip changed the value for {}", json);
+    // `from_str` uses its own streaming parser, which must agree with the
+    // derived `Deserialize`
+    let streamed: CadenceValue = serde_cadence::from_str(&json)?;
+    assert_eq!(parsed, streamed, "Streaming parse differs for {}", json);
+    // Asked to, it also rejects the duplicate keys the generator can produce
+    let options = ParseOptions { unique_keys: true };
+    match serde_cadence::from_str_value_with(&json, &options) {
+        Ok(checked) => assert_eq!(parsed, checked),
+        Err(_) => assert!(value.check_dictionary_keys().is_err()),
+    }
+    let borrowed = serde_cadence::from_str_borrowed(&json)?.into_owned();
+    assert_eq!(parsed, borrowed, "Borrowing parse differs for {}", json);
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    // Simple xorshift generator so the example runs without extra dependencies
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let iterations = 1_000;
+    for _ in 0..iterations {
+        let len = (next() % 512) as usize;
+        let data: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+        roundtrip(&data)?;
+    }
+    println!("{} round-trips completed without panics", iterations);
+
+    Ok(())
+}
@@ -4,6 +4,7 @@ use serde_cadence::{self, FromCadenceValue, Result, ToCadenceValue};
 
 // Define a struct and derive both Serde and our custom Cadence traits
 #[derive(Debug, Serialize, Deserialize, ToCadenceValue, FromCadenceValue)]
+#[allow(clippy::upper_case_acronyms)]
 struct NFT {
     id: String,
     name: String,
@@ -16,9 +17,27 @@ struct NFT {
 struct Metadata {
     artist: String,
     creation_date: String,
+    rarity: char,
     tags: Vec<String>,
 }
 
+// A unit struct carries no data; it encodes as an empty Struct and decodes
+// from one or from Void
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+struct Burned;
+
+// A C-like enum that Flow sends as the case name in a `String`
+#[derive(Debug, Clone, Copy, PartialEq, ToCadenceValue, FromCadenceValue)]
+#[cadence(repr = "string")]
+enum Status {
+    Listed,
+    Sold,
+    Delisted,
+}
+
+// Both directions stay implemented, or this fails to compile
+serde_cadence::assert_cadence_roundtrip!(NFT, Metadata, Status, Vec<NFT>);
+
 fn main() -> Result<()> {
     // Create an NFT instance
     let nft = NFT {
@@ -28,6 +47,7 @@ fn main() -> Result<()> {
         metadata: Metadata {
             artist: "DigitalArtist99".to_string(),
             creation_date: "2023-04-15".to_string(),
+            rarity: 'S',
             tags: vec![
                 "abstract".to_string(),
                 "colorful".to_string(),
@@ -49,5 +69,21 @@ fn main() -> Result<()> {
     let deserialized_nft = NFT::from_cadence_value(&cadence_value)?;
     println!("Deserialized NFT: {:#?}", deserialized_nft);
 
+    // Unit structs round-trip as an empty Struct and also accept `Void`
+    let burned = Burned.to_cadence_value()?;
+    println!("Unit struct: {}", serde_json::to_string(&burned)?);
+    assert_eq!(Burned::from_cadence_value(&burned)?, Burned);
+    let void: serde_cadence::CadenceValue = serde_cadence::from_str(r#"{"type":"Void"}"#)?;
+    assert_eq!(Burned::from_cadence_value(&void)?, Burned);
+
+    // String-represented enums round-trip through their case name
+    for status in [Status::Listed, Status::Sold, Status::Delisted] {
+        let encoded = status.to_cadence_value()?;
+        assert_eq!(encoded, serde_cadence::CadenceValue::String { value: format!("{:?}", status) });
+        assert_eq!(Status::from_cadence_value(&encoded)?, status);
+    }
+    let unknown = serde_cadence::CadenceValue::String { value: "Burned".to_string() };
+    assert!(Status::from_cadence_value(&unknown).is_err());
+
     Ok(())
 }
@@ -1,6 +1,6 @@
 // examples/basic.rs
 use serde::{Deserialize, Serialize};
-use serde_cadence::{CadenceValue, Result, ToCadenceValue};
+use serde_cadence::{CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue};
 
 // Define a Rust struct that we want to serialize to Cadence-JSON
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,19 +18,19 @@ impl ToCadenceValue for Person {
         use serde_cadence::{CompositeField, CompositeValue};
 
         let name_field = CompositeField {
-            name: "name".to_string(),
+            name: "name".into(),
             value: self.name.to_cadence_value()?,
         };
 
         let age_field = CompositeField {
-            name: "age".to_string(),
+            name: "age".into(),
             value: CadenceValue::UInt8 {
                 value: self.age.to_string(),
             },
         };
 
         let is_active_field = CompositeField {
-            name: "isActive".to_string(),
+            name: "isActive".into(),
             value: self.is_active.to_cadence_value()?,
         };
 
@@ -40,13 +40,13 @@ impl ToCadenceValue for Person {
         }
 
         let tags_field = CompositeField {
-            name: "tags".to_string(),
+            name: "tags".into(),
             value: CadenceValue::Array { value: tag_values },
         };
 
         Ok(CadenceValue::Struct {
             value: CompositeValue {
-                id: "Person".to_string(),
+                id: "Person".into(),
                 fields: vec![name_field, age_field, is_active_field, tags_field],
             },
         })
@@ -56,7 +56,7 @@ impl ToCadenceValue for Person {
 fn main() -> Result<()> {
     // Create a Person instance
     let person = Person {
-        name: "Alice".to_string(),
+        name: "Alice".into(),
         age: 30,
         is_active: true,
         tags: vec!["developer".to_string(), "rust".to_string()],
@@ -69,5 +69,69 @@ fn main() -> Result<()> {
     let json = serde_json::to_string_pretty(&cadence_value)?;
     println!("Cadence-JSON representation:\n{}", json);
 
+    // For logs, the Cadence-like rendering is much easier to read
+    assert_eq!(
+        cadence_value.to_pretty_cadence(),
+        r#"Person(name: "Alice", age: 30, isActive: true, tags: ["developer", "rust"])"#
+    );
+    let scores = serde_cadence::to_cadence_dictionary([("alice", 3u8), ("bob", 5)])?;
+    assert_eq!(scores.to_pretty_cadence(), r#"{"alice": 3, "bob": 5}"#);
+    println!("Pretty: {}", cadence_value.to_pretty_cadence());
+
+    // Fields can be moved out of a composite instead of cloned
+    let mut without_age = cadence_value.clone();
+    let age = without_age.take_field("age").expect("Person has an age");
+    assert_eq!(age.value, 30u8.to_cadence_value()?);
+    assert!(without_age.get("age").is_none());
+    assert!(without_age.take_field("age").is_none());
+
+    // Arrays typed `[AnyStruct]` can mix types; decode them as Vec<CadenceValue>
+    let mixed: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Array","value":[{"type":"String","value":"Alice"},{"type":"Int","value":"30"},{"type":"Bool","value":true}]}"#,
+    )?;
+    let items = Vec::<CadenceValue>::from_cadence_value(&mixed)?;
+    assert_eq!(items.len(), 3);
+    assert_eq!(String::from_cadence_value(&items[0])?, "Alice");
+    assert_eq!(i64::from_cadence_value(&items[1])?, 30);
+    assert!(bool::from_cadence_value(&items[2])?);
+
+    // Boxes are transparent, including boxed CadenceValues
+    let boxed = Box::<CadenceValue>::from_cadence_value(&items[0])?;
+    assert_eq!(boxed.to_cadence_value()?, items[0]);
+
+    // Integers are range-checked against the Rust type they decode into
+    let too_old = CadenceValue::UInt8 {
+        value: "300".to_string(),
+    };
+    match u8::from_cadence_value(&too_old) {
+        Err(Error::InvalidCadenceValue(message)) => println!("Rejected age: {}", message),
+        other => panic!("expected an out-of-range error, got {:?}", other),
+    }
+
+    // Unsigned integers also read Cadence's wrapping `Word*` types
+    let nonce = CadenceValue::Word64 {
+        value: u64::MAX.to_string(),
+    };
+    assert_eq!(u64::from_cadence_value(&nonce)?, u64::MAX);
+
+    // Producers may pad numbers differently; normalizing makes them comparable
+    let mut padded: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Array","value":[{"type":"UInt8","value":"+007"},{"type":"UFix64","value":"1.5"}]}"#,
+    )?;
+    padded.normalize_numbers();
+    assert_eq!(
+        padded,
+        CadenceValue::Array {
+            value: vec![
+                CadenceValue::UInt8 {
+                    value: "7".to_string()
+                },
+                CadenceValue::UFix64 {
+                    value: "1.50000000".to_string()
+                },
+            ]
+        }
+    );
+
     Ok(())
 }
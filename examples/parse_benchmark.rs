@@ -0,0 +1,78 @@
+// examples/parse_benchmark.rs
+//
+// Compares parsing a large Cadence-JSON document with `serde_cadence::from_str`,
+// which streams straight into a CadenceValue, against the derived
+// `Deserialize` used by `serde_json::from_str`, and against
+// `serde_cadence::from_str_borrowed`, which doesn't copy string payloads.
+// Run it with `--release`.
+use serde_cadence::{CadenceValue, CadenceValueRef, DictionaryEntry, Result};
+use std::borrow::Cow;
+use std::time::Instant;
+
+fn main() -> Result<()> {
+    // A dictionary of arrays of arrays, which nests a few levels deep
+    let value = CadenceValue::Dictionary {
+        value: (0..2_000)
+            .map(|i| DictionaryEntry {
+                key: CadenceValue::from(format!("key{}", i)),
+                value: CadenceValue::Array {
+                    value: (0..10)
+                        .map(|j| serde_cadence::cadence_value!([i as u64, j as u8, "item", true]))
+                        .collect(),
+                },
+            })
+            .collect(),
+    };
+    let json = serde_json::to_string(&value)?;
+    println!("Parsing {} bytes of Cadence-JSON", json.len());
+
+    let iterations = 20;
+
+    let start = Instant::now();
+    let mut derived = None;
+    for _ in 0..iterations {
+        derived = Some(serde_json::from_str::<CadenceValue>(&json)?);
+    }
+    println!(
+        "derived Deserialize: {:?} per parse",
+        start.elapsed() / iterations
+    );
+
+    let start = Instant::now();
+    let mut streamed = None;
+    for _ in 0..iterations {
+        streamed = Some(serde_cadence::from_str::<CadenceValue>(&json)?);
+    }
+    println!(
+        "streaming parser:    {:?} per parse",
+        start.elapsed() / iterations
+    );
+
+    let start = Instant::now();
+    let mut borrowed = None;
+    for _ in 0..iterations {
+        borrowed = Some(serde_cadence::from_str_borrowed(&json)?);
+    }
+    println!(
+        "borrowing parser:    {:?} per parse",
+        start.elapsed() / iterations
+    );
+
+    assert_eq!(derived, streamed);
+    let borrowed = borrowed.expect("at least one iteration");
+
+    // The first key's string payload points into `json` rather than a copy
+    let CadenceValueRef::Dictionary { value: entries } = &borrowed else {
+        panic!("expected a dictionary");
+    };
+    let CadenceValueRef::String {
+        value: Cow::Borrowed(key),
+    } = &entries[0].key
+    else {
+        panic!("expected a borrowed string key");
+    };
+    assert!(json.as_bytes().as_ptr_range().contains(&key.as_ptr()));
+
+    assert_eq!(Some(borrowed.into_owned()), streamed);
+    Ok(())
+}
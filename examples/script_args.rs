@@ -0,0 +1,58 @@
+use serde_cadence::{
+    CadenceValue, ConversionOptions, EncodeOptions, Error, NumberMapping, ToCadenceValue, UFix64,
+    encode_arguments, to_cadence_value_with, value_to_cadence_value_with,
+};
+
+fn main() -> Result<(), Error> {
+    // A script such as
+    //
+    //     access(all) fun main(id: UInt64, greeting: String, active: Bool, amount: UFix64) { ... }
+    //
+    // takes its arguments as a list of Cadence-JSON values, one per parameter
+    let amount: UFix64 = "12.5".parse()?;
+    let args: [&dyn ToCadenceValue; 4] = [&42u64, &"hello", &true, &amount];
+    let encoded = encode_arguments(&args)?;
+
+    for (index, argument) in encoded.iter().enumerate() {
+        println!("Argument {}: {}", index, argument);
+    }
+
+    assert_eq!(encoded[0], r#"{"type":"UInt64","value":"42"}"#);
+    assert_eq!(encoded[1], r#"{"type":"String","value":"hello"}"#);
+    assert_eq!(encoded[2], r#"{"type":"Bool","value":true}"#);
+    assert_eq!(encoded[3], r#"{"type":"UFix64","value":"12.50000000"}"#);
+    println!("All arguments encoded as expected!");
+
+    // Untyped JSON often carries whole token amounts as plain integers; the
+    // UFix64 hint reads them as fixed-point instead of Int
+    let options = ConversionOptions {
+        number_mapping: NumberMapping::UFix64,
+        ..Default::default()
+    };
+    let amount = value_to_cadence_value_with(&serde_json::json!(100), &options)?;
+    assert_eq!(
+        amount,
+        CadenceValue::UFix64 {
+            value: "100.00000000".to_string()
+        }
+    );
+    assert!(value_to_cadence_value_with(&serde_json::json!(-1), &options).is_err());
+    println!("Integer amount read as {:?}", amount);
+
+    // A parameter declared as `UInt` needs the arbitrary-precision type even
+    // when the Rust side holds the number in a u64
+    let options = EncodeOptions {
+        unsigned: Some(NumberMapping::UInt),
+        ..Default::default()
+    };
+    let supply = to_cadence_value_with(&1_000_000u64, &options)?;
+    assert_eq!(
+        supply,
+        CadenceValue::UInt {
+            value: "1000000".to_string()
+        }
+    );
+    println!("u64 encoded as {:?}", supply);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// examples/type_encoding.rs
+//
+// Checks that CadenceType serializes to the exact JSON-Cadence type encoding
+// Flow uses, and parses back to the same value.
+use serde::{Deserialize, Serialize};
+use serde_cadence::{CadenceType, CadenceValue, FieldType, ParameterType, Result};
+use std::fs;
+use std::path::Path;
+
+fn check(ty: &CadenceType, flow_json: &str) -> Result<()> {
+    let json = serde_json::to_string(ty)?;
+    assert_eq!(json, flow_json, "Encoding differs from Flow's");
+    let parsed: CadenceType = serde_json::from_str(flow_json)?;
+    assert_eq!(&parsed, ty, "Parsing Flow's encoding changed the type");
+    println!("{}", json);
+    Ok(())
+}
+
+fn golden<T>(dir: &str) -> Result<()>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples/golden")
+        .join(dir);
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .expect("golden directory is readable")
+        .map(|entry| entry.expect("golden entry is readable").path())
+        .collect();
+    paths.sort();
+    for path in paths {
+        let flow_json = fs::read_to_string(&path).expect("golden file is readable");
+        let parsed: T = serde_json::from_str(&flow_json)?;
+        let json = serde_json::to_string(&parsed)?;
+        assert_eq!(
+            json,
+            flow_json.trim_end(),
+            "Re-encoding {} differs",
+            path.display()
+        );
+        println!("{}: ok", path.display());
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    // A struct with an initializer and two fields
+    let nft = CadenceType::struct_type(
+        "A.0000000000000001.NFT.Token",
+        vec![vec![
+            ParameterType::new("_", "id", CadenceType::UInt64),
+            ParameterType::new("name", "name", CadenceType::String),
+        ]],
+        vec![
+            FieldType::new("id", CadenceType::UInt64),
+            FieldType::new("name", CadenceType::String),
+        ],
+    );
+    check(
+        &nft,
+        r#"{"kind":"Struct","type":"","typeID":"A.0000000000000001.NFT.Token","initializers":[[{"label":"_","id":"id","type":{"kind":"UInt64"}},{"label":"name","id":"name","type":{"kind":"String"}}]],"fields":[{"id":"id","type":{"kind":"UInt64"}},{"id":"name","type":{"kind":"String"}}]}"#,
+    )?;
+
+    // Function types use `typeID` and `return`
+    let function = CadenceType::Function {
+        type_id: "view fun(String): Int".to_string(),
+        parameters: vec![ParameterType::new("_", "x", CadenceType::String)],
+        purity: Some("view".to_string()),
+        return_: Box::new(CadenceType::Int),
+    };
+    check(
+        &function,
+        r#"{"kind":"Function","typeID":"view fun(String): Int","parameters":[{"label":"_","id":"x","type":{"kind":"String"}}],"purity":"view","return":{"kind":"Int"}}"#,
+    )?;
+
+    // Authorized references name their entitlements by `typeID`
+    check(
+        &CadenceType::auth_reference(
+            ["A.0000000000000001.FungibleToken.Withdraw"],
+            CadenceType::Optional {
+                type_: Box::new(CadenceType::UFix64),
+            },
+        ),
+        r#"{"kind":"Reference","authorization":{"kind":"EntitlementConjunctionSet","entitlements":[{"kind":"Entitlement","typeID":"A.0000000000000001.FungibleToken.Withdraw"}]},"type":{"kind":"Optional","type":{"kind":"UFix64"}}}"#,
+    )?;
+
+    // Golden files holding Flow's encoding of types and of values that
+    // embed types, which must survive a parse and re-encode unchanged
+    golden::<CadenceType>("types")?;
+    golden::<CadenceValue>("values")?;
+
+    Ok(())
+}
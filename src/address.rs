@@ -0,0 +1,155 @@
+// src/address.rs
+
+// This file contains the Address newtype and validation and decoding of Flow
+// account addresses, which Cadence-JSON encodes as `0x`-prefixed hex strings
+// of up to 8 bytes
+
+use crate::{CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Flow addresses are 8 bytes long
+const MAX_ADDRESS_DIGITS: usize = 16;
+
+/// A Flow account address, stored as its 8 bytes.
+///
+/// Parsing accepts 1 to 16 hex digits with or without a `0x` prefix, so `0x1`
+/// and `0x0000000000000001` are the same address. It always displays in the
+/// full form, e.g. `0x0000000000000001`. Being `Hash` and `Ord`, it can key a
+/// `HashMap` or `BTreeMap` decoded from an `{Address: T}` dictionary.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Address(pub [u8; 8]);
+
+impl Address {
+    /// Returns the address as a `u64`
+    pub const fn to_u64(self) -> u64 {
+        u64::from_be_bytes(self.0)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:016x}", self.to_u64())
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_address(s)
+            .map(Address)
+            .ok_or_else(|| Error::Custom(format!("Failed to parse Address: {}", s)))
+    }
+}
+
+impl From<u64> for Address {
+    fn from(value: u64) -> Self {
+        Address(value.to_be_bytes())
+    }
+}
+
+impl ToCadenceValue for Address {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::Address {
+            value: self.to_string(),
+        })
+    }
+}
+
+impl FromCadenceValue for Address {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Address { value } => value.parse(),
+            _ => Err(Error::TypeMismatch {
+                expected: "Address".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl From<Address> for CadenceValue {
+    fn from(value: Address) -> Self {
+        CadenceValue::Address {
+            value: value.to_string(),
+        }
+    }
+}
+
+// Serialized as its display string, like in Cadence-JSON
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let address = String::deserialize(deserializer)?;
+        address.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl CadenceValue {
+    /// Decodes an `Address` value into its 8 bytes, big-endian, left-padding
+    /// short addresses such as `0x1` with zeros.
+    ///
+    /// The `0x` prefix is optional. Returns None for other variants or if the
+    /// value isn't 1 to 16 hex digits.
+    pub fn as_address_bytes(&self) -> Option<[u8; 8]> {
+        match self {
+            CadenceValue::Address { value } => parse_address(value),
+            _ => None,
+        }
+    }
+}
+
+// Decodes an address with an optional `0x` prefix into its bytes
+fn parse_address(address: &str) -> Option<[u8; 8]> {
+    let digits = address.strip_prefix("0x").unwrap_or(address);
+    if !is_hex_digits(digits) {
+        return None;
+    }
+    // At most 16 hex digits always fit in a u64
+    u64::from_str_radix(digits, 16).ok().map(u64::to_be_bytes)
+}
+
+fn is_hex_digits(digits: &str) -> bool {
+    !digits.is_empty()
+        && digits.len() <= MAX_ADDRESS_DIGITS
+        && digits.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Returns true for a `0x`-prefixed hex string of 1 to 16 digits
+#[cfg(feature = "strict-address")]
+pub(crate) fn is_valid_address(address: &str) -> bool {
+    address.strip_prefix("0x").is_some_and(is_hex_digits)
+}
+
+// Used for the `Address` variant with the `strict-address` feature, so that
+// malformed addresses are rejected while parsing
+#[cfg(feature = "strict-address")]
+pub(crate) fn deserialize_strict<'de, D>(deserializer: D) -> core::result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let address = String::deserialize(deserializer)?;
+    check_strict(&address)?;
+    Ok(address)
+}
+
+#[cfg(feature = "strict-address")]
+pub(crate) fn check_strict<E: serde::de::Error>(address: &str) -> core::result::Result<(), E> {
+    if is_valid_address(address) {
+        Ok(())
+    } else {
+        Err(E::custom(format!(
+            "invalid Cadence address {:?}, expected 0x followed by up to {} hex digits",
+            address, MAX_ADDRESS_DIGITS
+        )))
+    }
+}
@@ -0,0 +1,307 @@
+// src/borrowed.rs
+
+// This file contains CadenceValueRef, a counterpart of CadenceValue that
+// borrows its string payloads from the parsed input instead of copying them,
+// for reading large responses
+
+use crate::{
+    CadenceValue, CapabilityValue, CompositeField, CompositeValue, DictionaryEntry, FunctionValue,
+    Name, PathValue, RangeValue, TypeValue,
+};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// A Cadence value whose string payloads borrow from the JSON it was parsed
+/// from.
+///
+/// Parse one with [`from_slice_borrowed`](crate::from_slice_borrowed) or
+/// [`from_str_borrowed`](crate::from_str_borrowed). Strings are borrowed
+/// unless they contain escape sequences, in which case they're owned. Paths,
+/// types, ranges, capabilities and functions are rare enough that they're
+/// parsed into their owned forms. Call [`CadenceValueRef::into_owned`] to get
+/// a [`CadenceValue`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CadenceValueRef<'a> {
+    Void {},
+    Optional {
+        value: Option<Box<CadenceValueRef<'a>>>,
+    },
+    Bool {
+        value: bool,
+    },
+    String {
+        value: Cow<'a, str>,
+    },
+    Character {
+        value: Cow<'a, str>,
+    },
+    Address {
+        value: Cow<'a, str>,
+    },
+    Int {
+        value: Cow<'a, str>,
+    },
+    Int8 {
+        value: Cow<'a, str>,
+    },
+    Int16 {
+        value: Cow<'a, str>,
+    },
+    Int32 {
+        value: Cow<'a, str>,
+    },
+    Int64 {
+        value: Cow<'a, str>,
+    },
+    Int128 {
+        value: Cow<'a, str>,
+    },
+    Int256 {
+        value: Cow<'a, str>,
+    },
+    UInt {
+        value: Cow<'a, str>,
+    },
+    UInt8 {
+        value: Cow<'a, str>,
+    },
+    UInt16 {
+        value: Cow<'a, str>,
+    },
+    UInt32 {
+        value: Cow<'a, str>,
+    },
+    UInt64 {
+        value: Cow<'a, str>,
+    },
+    UInt128 {
+        value: Cow<'a, str>,
+    },
+    UInt256 {
+        value: Cow<'a, str>,
+    },
+    Word8 {
+        value: Cow<'a, str>,
+    },
+    Word16 {
+        value: Cow<'a, str>,
+    },
+    Word32 {
+        value: Cow<'a, str>,
+    },
+    Word64 {
+        value: Cow<'a, str>,
+    },
+    Word128 {
+        value: Cow<'a, str>,
+    },
+    Word256 {
+        value: Cow<'a, str>,
+    },
+    Fix64 {
+        value: Cow<'a, str>,
+    },
+    UFix64 {
+        value: Cow<'a, str>,
+    },
+    Array {
+        value: Vec<CadenceValueRef<'a>>,
+    },
+    Dictionary {
+        value: Vec<DictionaryEntryRef<'a>>,
+    },
+    Struct {
+        value: CompositeValueRef<'a>,
+    },
+    Resource {
+        value: CompositeValueRef<'a>,
+    },
+    Event {
+        value: CompositeValueRef<'a>,
+    },
+    Contract {
+        value: CompositeValueRef<'a>,
+    },
+    Enum {
+        value: CompositeValueRef<'a>,
+    },
+    Path {
+        value: PathValue,
+    },
+    Type {
+        value: TypeValue,
+    },
+    InclusiveRange {
+        value: RangeValue,
+    },
+    Capability {
+        value: CapabilityValue,
+    },
+    Function {
+        value: FunctionValue,
+    },
+}
+
+/// A borrowed [`DictionaryEntry`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryEntryRef<'a> {
+    pub key: CadenceValueRef<'a>,
+    pub value: CadenceValueRef<'a>,
+}
+
+/// A borrowed [`CompositeValue`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompositeValueRef<'a> {
+    pub id: Cow<'a, str>,
+    pub fields: Vec<CompositeFieldRef<'a>>,
+}
+
+/// A borrowed [`CompositeField`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompositeFieldRef<'a> {
+    pub name: Cow<'a, str>,
+    pub value: CadenceValueRef<'a>,
+}
+
+impl CadenceValueRef<'_> {
+    /// Copies every borrowed payload, producing the equivalent `CadenceValue`
+    pub fn into_owned(self) -> CadenceValue {
+        match self {
+            CadenceValueRef::Void {} => CadenceValue::Void {},
+            CadenceValueRef::Optional { value } => CadenceValue::Optional {
+                value: value.map(|inner| Box::new(inner.into_owned())),
+            },
+            CadenceValueRef::Bool { value } => CadenceValue::Bool { value },
+            CadenceValueRef::String { value } => CadenceValue::String {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Character { value } => CadenceValue::Character {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Address { value } => CadenceValue::Address {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Int { value } => CadenceValue::Int {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Int8 { value } => CadenceValue::Int8 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Int16 { value } => CadenceValue::Int16 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Int32 { value } => CadenceValue::Int32 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Int64 { value } => CadenceValue::Int64 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Int128 { value } => CadenceValue::Int128 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Int256 { value } => CadenceValue::Int256 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UInt { value } => CadenceValue::UInt {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UInt8 { value } => CadenceValue::UInt8 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UInt16 { value } => CadenceValue::UInt16 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UInt32 { value } => CadenceValue::UInt32 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UInt64 { value } => CadenceValue::UInt64 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UInt128 { value } => CadenceValue::UInt128 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UInt256 { value } => CadenceValue::UInt256 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Word8 { value } => CadenceValue::Word8 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Word16 { value } => CadenceValue::Word16 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Word32 { value } => CadenceValue::Word32 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Word64 { value } => CadenceValue::Word64 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Word128 { value } => CadenceValue::Word128 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Word256 { value } => CadenceValue::Word256 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Fix64 { value } => CadenceValue::Fix64 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::UFix64 { value } => CadenceValue::UFix64 {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Array { value } => CadenceValue::Array {
+                value: value.into_iter().map(CadenceValueRef::into_owned).collect(),
+            },
+            CadenceValueRef::Dictionary { value } => CadenceValue::Dictionary {
+                value: value
+                    .into_iter()
+                    .map(|entry| DictionaryEntry {
+                        key: entry.key.into_owned(),
+                        value: entry.value.into_owned(),
+                    })
+                    .collect(),
+            },
+            CadenceValueRef::Struct { value } => CadenceValue::Struct {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Resource { value } => CadenceValue::Resource {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Event { value } => CadenceValue::Event {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Contract { value } => CadenceValue::Contract {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Enum { value } => CadenceValue::Enum {
+                value: value.into_owned(),
+            },
+            CadenceValueRef::Path { value } => CadenceValue::Path { value },
+            CadenceValueRef::Type { value } => CadenceValue::Type { value },
+            CadenceValueRef::InclusiveRange { value } => CadenceValue::InclusiveRange { value },
+            CadenceValueRef::Capability { value } => CadenceValue::Capability { value },
+            CadenceValueRef::Function { value } => CadenceValue::Function { value },
+        }
+    }
+}
+
+impl CompositeValueRef<'_> {
+    pub fn into_owned(self) -> CompositeValue {
+        CompositeValue {
+            id: Name::from(self.id),
+            fields: self
+                .fields
+                .into_iter()
+                .map(|field| CompositeField {
+                    name: Name::from(field.name),
+                    value: field.value.into_owned(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<CadenceValueRef<'_>> for CadenceValue {
+    fn from(value: CadenceValueRef<'_>) -> Self {
+        value.into_owned()
+    }
+}
@@ -0,0 +1,78 @@
+// src/bridge.rs
+
+// This file contains SerdeBridge, which lets plain serde types go through the
+// Cadence traits via their serde_json representation
+
+use crate::{
+    CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue, cadence_value_to_value,
+    value_to_cadence_value,
+};
+use alloc::string::ToString;
+use core::ops::{Deref, DerefMut};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Wraps a type that only implements serde's traits.
+///
+/// Encoding serializes the inner value with serde_json and converts the result
+/// with [`value_to_cadence_value`]; decoding goes the other way through
+/// [`cadence_value_to_value`]. Structs therefore become `String`-keyed
+/// dictionaries rather than Cadence composites. Dictionary keys become JSON
+/// object keys, which serde_json parses back into integer key types, so maps
+/// such as `HashMap<u64, String>` still round-trip.
+///
+/// Values out of range for the target type, such as a `UInt8` of `300` read
+/// into a `u8`, fail with [`Error::InvalidCadenceValue`] carrying serde's
+/// message. Other mismatches, like a `String` where a number is expected or a
+/// missing field, fail with [`Error::SerdeJson`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SerdeBridge<T>(pub T);
+
+impl<T> SerdeBridge<T> {
+    /// Returns the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize + Sync> ToCadenceValue for SerdeBridge<T> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        let value = serde_json::to_value(&self.0)?;
+        value_to_cadence_value(&value)
+    }
+}
+
+impl<T: DeserializeOwned + Sync> FromCadenceValue for SerdeBridge<T> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let value = cadence_value_to_value(value)?;
+        serde_json::from_value(value)
+            .map(SerdeBridge)
+            .map_err(bridge_error)
+    }
+}
+
+// serde_json reports every data error through one type, so values the target
+// type can't hold are told apart by serde's `invalid value` wording
+fn bridge_error(error: serde_json::Error) -> Error {
+    let message = error.to_string();
+    if message.starts_with("invalid value: ") {
+        Error::InvalidCadenceValue(message)
+    } else {
+        Error::SerdeJson(error)
+    }
+}
+
+impl<T> Deref for SerdeBridge<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for SerdeBridge<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,134 @@
+// src/bytes.rs
+
+// This file contains the Bytes newtype for binary data such as signatures and
+// hashes, which Cadence represents as `[UInt8]`
+
+use crate::{CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue};
+use serde::{Deserialize, Serialize};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+/// A byte buffer encoded as a Cadence `[UInt8]`.
+///
+/// Decoding also accepts a hex `String` (with or without a `0x` prefix), which
+/// is how binary data is often passed around off-chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Bytes {
+    /// Encodes the bytes as a lowercase hex `String` value instead of `[UInt8]`
+    pub fn to_hex_value(&self) -> CadenceValue {
+        CadenceValue::String {
+            value: self.to_hex(),
+        }
+    }
+
+    /// Returns the bytes as a lowercase hex string without a prefix
+    pub fn to_hex(&self) -> String {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut hex = String::with_capacity(self.0.len() * 2);
+        for byte in &self.0 {
+            hex.push(DIGITS[(byte >> 4) as usize] as char);
+            hex.push(DIGITS[(byte & 0x0f) as usize] as char);
+        }
+        hex
+    }
+
+    /// Parses a hex string, with or without a `0x` prefix
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex);
+        if !hex.len().is_multiple_of(2) {
+            return Err(Error::Custom(format!(
+                "Failed to parse hex bytes: odd length {}",
+                hex.len()
+            )));
+        }
+        let mut bytes = Vec::with_capacity(hex.len() / 2);
+        for pair in hex.as_bytes().chunks(2) {
+            let byte = core::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| Error::Custom(format!("Failed to parse hex bytes: {}", hex)))?;
+            bytes.push(byte);
+        }
+        Ok(Bytes(bytes))
+    }
+}
+
+impl ToCadenceValue for Bytes {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        let values = self
+            .0
+            .iter()
+            .map(|byte| CadenceValue::UInt8 {
+                value: byte.to_string(),
+            })
+            .collect();
+        Ok(CadenceValue::Array { value: values })
+    }
+}
+
+impl FromCadenceValue for Bytes {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Array { value } => {
+                let mut bytes = Vec::with_capacity(value.len());
+                for item in value {
+                    match item {
+                        CadenceValue::UInt8 { value } => bytes.push(
+                            value
+                                .parse()
+                                .map_err(|e| Error::Custom(format!("Failed to parse u8: {}", e)))?,
+                        ),
+                        _ => {
+                            return Err(Error::TypeMismatch {
+                                expected: "UInt8".to_string(),
+                                got: format!("{:?}", item),
+                            });
+                        }
+                    }
+                }
+                Ok(Bytes(bytes))
+            }
+            CadenceValue::String { value } => Bytes::from_hex(value),
+            _ => Err(Error::TypeMismatch {
+                expected: "Array".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(bytes: &[u8]) -> Self {
+        Bytes(bytes.to_vec())
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Self {
+        bytes.0
+    }
+}
+
+impl Deref for Bytes {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Bytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
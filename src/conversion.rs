@@ -0,0 +1,634 @@
+// src/conversion.rs
+
+// This file contains conversions between plain serde_json::Value trees and
+// CadenceValue, for callers that work with untyped JSON
+
+use crate::value::parse_fixed_point;
+use crate::{
+    CadenceValue, CompositeField, CompositeValue, DictionaryEntry, Error, Fix64, FromCadenceValue,
+    PathDomain, Result, ToCadenceValue, UFix64,
+};
+use serde::Deserialize;
+use serde::de::value::{BorrowedStrDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{Deserializer, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use serde_json::{Map, Number, Value};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Selects the Cadence type that JSON integers are converted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberMapping {
+    /// Integers that fit in `i64` become `Int`, larger ones become `UInt`
+    #[default]
+    Auto,
+    Int,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Int128,
+    Int256,
+    UInt,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    UInt128,
+    UInt256,
+    Word8,
+    Word16,
+    Word32,
+    Word64,
+    /// Integers are read as whole fixed-point amounts, so `100` becomes
+    /// `Fix64` `"100.00000000"`; fractional numbers are unaffected
+    Fix64,
+    /// Like [`NumberMapping::Fix64`] but produces `UFix64`, and also maps
+    /// fractional numbers to `UFix64` instead of `Fix64`
+    UFix64,
+}
+
+impl NumberMapping {
+    // Builds the target value from integer digits, checking that the number
+    // fits the type
+    pub(crate) fn integer_value(self, value: String) -> Result<CadenceValue> {
+        if let NumberMapping::Fix64 | NumberMapping::UFix64 = self {
+            return self.fixed_point_value(&value);
+        }
+        let negative = value.starts_with('-');
+        let fits = match self {
+            NumberMapping::Auto | NumberMapping::Int | NumberMapping::Int256 => true,
+            NumberMapping::Int8 => value.parse::<i8>().is_ok(),
+            NumberMapping::Int16 => value.parse::<i16>().is_ok(),
+            NumberMapping::Int32 => value.parse::<i32>().is_ok(),
+            NumberMapping::Int64 => value.parse::<i64>().is_ok(),
+            NumberMapping::Int128 => value.parse::<i128>().is_ok(),
+            NumberMapping::UInt | NumberMapping::UInt256 => !negative,
+            NumberMapping::UInt8 | NumberMapping::Word8 => value.parse::<u8>().is_ok(),
+            NumberMapping::UInt16 | NumberMapping::Word16 => value.parse::<u16>().is_ok(),
+            NumberMapping::UInt32 | NumberMapping::Word32 => value.parse::<u32>().is_ok(),
+            NumberMapping::UInt64 | NumberMapping::Word64 => value.parse::<u64>().is_ok(),
+            NumberMapping::UInt128 => value.parse::<u128>().is_ok(),
+            // Handled by fixed_point_value above
+            NumberMapping::Fix64 | NumberMapping::UFix64 => unreachable!(),
+        };
+        if !fits {
+            return Err(Error::InvalidCadenceValue(format!(
+                "{} is out of range for {:?}",
+                value, self
+            )));
+        }
+        Ok(match self {
+            NumberMapping::Auto if negative || value.parse::<i64>().is_ok() => {
+                CadenceValue::Int { value }
+            }
+            NumberMapping::Auto => CadenceValue::UInt { value },
+            NumberMapping::Int => CadenceValue::Int { value },
+            NumberMapping::Int8 => CadenceValue::Int8 { value },
+            NumberMapping::Int16 => CadenceValue::Int16 { value },
+            NumberMapping::Int32 => CadenceValue::Int32 { value },
+            NumberMapping::Int64 => CadenceValue::Int64 { value },
+            NumberMapping::Int128 => CadenceValue::Int128 { value },
+            NumberMapping::Int256 => CadenceValue::Int256 { value },
+            NumberMapping::UInt => CadenceValue::UInt { value },
+            NumberMapping::UInt8 => CadenceValue::UInt8 { value },
+            NumberMapping::UInt16 => CadenceValue::UInt16 { value },
+            NumberMapping::UInt32 => CadenceValue::UInt32 { value },
+            NumberMapping::UInt64 => CadenceValue::UInt64 { value },
+            NumberMapping::UInt128 => CadenceValue::UInt128 { value },
+            NumberMapping::UInt256 => CadenceValue::UInt256 { value },
+            NumberMapping::Word8 => CadenceValue::Word8 { value },
+            NumberMapping::Word16 => CadenceValue::Word16 { value },
+            NumberMapping::Word32 => CadenceValue::Word32 { value },
+            NumberMapping::Word64 => CadenceValue::Word64 { value },
+            // Handled by fixed_point_value above
+            NumberMapping::Fix64 | NumberMapping::UFix64 => unreachable!(),
+        })
+    }
+
+    // Builds a fixed-point value from the decimal text of a number
+    fn fixed_point_value(self, value: &str) -> Result<CadenceValue> {
+        let out_of_range =
+            || Error::InvalidCadenceValue(format!("{} is out of range for {:?}", value, self));
+        match self {
+            NumberMapping::UFix64 => {
+                Ok(value.parse::<UFix64>().map_err(|_| out_of_range())?.into())
+            }
+            _ => Ok(value.parse::<Fix64>().map_err(|_| out_of_range())?.into()),
+        }
+    }
+}
+
+/// Default nesting limit for conversions, see [`ConversionOptions::max_depth`]
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// Options controlling how plain JSON is converted into Cadence values
+#[derive(Debug, Clone)]
+pub struct ConversionOptions {
+    /// Target type for JSON integers
+    pub number_mapping: NumberMapping,
+    /// Only treat objects as Cadence-JSON when their `type` names a Cadence
+    /// value type; objects like `{"type": "premium", "value": ...}` then
+    /// become dictionaries instead of failing to parse
+    pub strict: bool,
+    /// Maximum nesting depth accepted before failing, which keeps untrusted
+    /// input from overflowing the stack
+    pub max_depth: usize,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        ConversionOptions {
+            number_mapping: NumberMapping::default(),
+            strict: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+/// Options for [`to_cadence_value_with`](crate::to_cadence_value_with), which
+/// pin the Cadence types Rust integers encode as.
+///
+/// A Flow `UInt` decoded into a `u64` would otherwise come back as a `UInt64`;
+/// setting `unsigned` to [`NumberMapping::UInt`] keeps it a `UInt`. Only the
+/// `ToCadenceValue` impls of `u8` through `u64`, `i8` through `i64` and their
+/// `NonZero` forms follow the options, so a `Vec<u8>` is pinned too. Values
+/// built directly keep their types: a derived enum's `UInt8` `rawValue`, the
+/// elements of [`Bytes`](crate::Bytes), and numbers inside a `CadenceValue`
+/// or `serde_json::Value`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EncodeOptions {
+    /// Target type for `u8` through `u64`, or None to keep `UInt8`-`UInt64`
+    pub unsigned: Option<NumberMapping>,
+    /// Target type for `i8` through `i64`, or None to keep `Int8`-`Int64`
+    pub signed: Option<NumberMapping>,
+}
+
+// The options the integer impls consult while `to_cadence_value_with` runs
+#[cfg(feature = "std")]
+std::thread_local! {
+    static ENCODE_OPTIONS: core::cell::Cell<EncodeOptions> = const {
+        core::cell::Cell::new(EncodeOptions {
+            unsigned: None,
+            signed: None,
+        })
+    };
+}
+
+#[cfg(feature = "std")]
+impl EncodeOptions {
+    // Runs `f` with these options in effect on the current thread, restoring
+    // the previous ones afterwards even if `f` panics
+    pub(crate) fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(EncodeOptions);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                ENCODE_OPTIONS.with(|options| options.set(self.0));
+            }
+        }
+
+        let _restore = Restore(ENCODE_OPTIONS.with(|options| options.replace(self)));
+        f()
+    }
+
+    // The options in effect on the current thread
+    pub(crate) fn current() -> EncodeOptions {
+        ENCODE_OPTIONS.with(core::cell::Cell::get)
+    }
+}
+
+// The `type` tags a Cadence-JSON value can carry
+const CADENCE_VALUE_TYPES: &[&str] = &[
+    "Void",
+    "Optional",
+    "Bool",
+    "String",
+    "Character",
+    "Address",
+    "Int",
+    "Int8",
+    "Int16",
+    "Int32",
+    "Int64",
+    "Int128",
+    "Int256",
+    "UInt",
+    "UInt8",
+    "UInt16",
+    "UInt32",
+    "UInt64",
+    "UInt128",
+    "UInt256",
+    "Word8",
+    "Word16",
+    "Word32",
+    "Word64",
+    "Word128",
+    "Word256",
+    "Fix64",
+    "UFix64",
+    "Array",
+    "Dictionary",
+    "Struct",
+    "Resource",
+    "Event",
+    "Contract",
+    "Enum",
+    "Path",
+    "Type",
+    "InclusiveRange",
+    "Capability",
+    "Function",
+];
+
+/// Converts an arbitrary JSON value into a CadenceValue.
+///
+/// Objects that already look like Cadence-JSON (a string `type` field next to a
+/// `value` field) are parsed as such; every other object becomes a dictionary
+/// with `String` keys.
+pub fn value_to_cadence_value(value: &Value) -> Result<CadenceValue> {
+    value_to_cadence_value_with(value, &ConversionOptions::default())
+}
+
+/// Converts an arbitrary JSON value into a CadenceValue using the given options
+pub fn value_to_cadence_value_with(
+    value: &Value,
+    options: &ConversionOptions,
+) -> Result<CadenceValue> {
+    json_to_cadence_value(value, options, options.max_depth)
+}
+
+// `remaining` is the nesting budget left before `max_depth` is exceeded
+fn json_to_cadence_value(
+    value: &Value,
+    options: &ConversionOptions,
+    remaining: usize,
+) -> Result<CadenceValue> {
+    let remaining = descend(remaining)?;
+    match value {
+        Value::Null => Ok(CadenceValue::Optional { value: None }),
+        Value::Bool(b) => Ok(CadenceValue::Bool { value: *b }),
+        Value::Number(n) => number_to_cadence_value(n, options),
+        Value::String(s) => Ok(CadenceValue::String { value: s.clone() }),
+        Value::Array(items) => {
+            let mut values = Vec::with_capacity(items.len());
+            for item in items {
+                values.push(json_to_cadence_value(item, options, remaining)?);
+            }
+            Ok(CadenceValue::Array { value: values })
+        }
+        Value::Object(map) => {
+            if is_structured_cadence_value(map, options.strict) {
+                return parse_structured_cadence_value(value, options, remaining);
+            }
+            let mut entries = Vec::with_capacity(map.len());
+            for (key, value) in map {
+                entries.push(DictionaryEntry {
+                    key: CadenceValue::String { value: key.clone() },
+                    value: json_to_cadence_value(value, options, remaining)?,
+                });
+            }
+            Ok(CadenceValue::Dictionary { value: entries })
+        }
+    }
+}
+
+/// Converts a CadenceValue into plain JSON, dropping the Cadence type tags.
+///
+/// Integers become JSON numbers when they fit in 64 bits and strings otherwise,
+/// and fixed-point values become numbers only when an `f64` holds them to all
+/// 8 decimals; composites become objects keyed by field name.
+pub fn cadence_value_to_value(value: &CadenceValue) -> Result<Value> {
+    cadence_value_to_value_with(value, &ConversionOptions::default())
+}
+
+/// Converts a CadenceValue into plain JSON, honouring the `max_depth` option
+pub fn cadence_value_to_value_with(
+    value: &CadenceValue,
+    options: &ConversionOptions,
+) -> Result<Value> {
+    cadence_to_json_value(value, options.max_depth)
+}
+
+// Plain JSON goes through the default conversions above, so objects become
+// `String`-keyed dictionaries and integers become `Int` or `UInt`
+impl ToCadenceValue for Value {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        value_to_cadence_value(self)
+    }
+}
+
+impl FromCadenceValue for Value {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        cadence_value_to_value(value)
+    }
+}
+
+fn cadence_to_json_value(value: &CadenceValue, remaining: usize) -> Result<Value> {
+    let remaining = descend(remaining)?;
+    match value {
+        CadenceValue::Void {} => Ok(Value::Null),
+        CadenceValue::Optional { value } => match value {
+            Some(inner) => cadence_to_json_value(inner, remaining),
+            None => Ok(Value::Null),
+        },
+        CadenceValue::Bool { value } => Ok(Value::Bool(*value)),
+        CadenceValue::String { value }
+        | CadenceValue::Character { value }
+        | CadenceValue::Address { value } => Ok(Value::String(value.clone())),
+        CadenceValue::Int { value }
+        | CadenceValue::Int8 { value }
+        | CadenceValue::Int16 { value }
+        | CadenceValue::Int32 { value }
+        | CadenceValue::Int64 { value }
+        | CadenceValue::Int128 { value }
+        | CadenceValue::Int256 { value }
+        | CadenceValue::UInt { value }
+        | CadenceValue::UInt8 { value }
+        | CadenceValue::UInt16 { value }
+        | CadenceValue::UInt32 { value }
+        | CadenceValue::UInt64 { value }
+        | CadenceValue::UInt128 { value }
+        | CadenceValue::UInt256 { value }
+        | CadenceValue::Word8 { value }
+        | CadenceValue::Word16 { value }
+        | CadenceValue::Word32 { value }
+        | CadenceValue::Word64 { value }
+        | CadenceValue::Word128 { value }
+        | CadenceValue::Word256 { value } => Ok(integer_to_value(value)),
+        CadenceValue::Fix64 { value } | CadenceValue::UFix64 { value } => {
+            Ok(fixed_point_to_value(value))
+        }
+        CadenceValue::Array { value } => {
+            let mut items = Vec::with_capacity(value.len());
+            for item in value {
+                items.push(cadence_to_json_value(item, remaining)?);
+            }
+            Ok(Value::Array(items))
+        }
+        CadenceValue::Dictionary { value } => value
+            .iter()
+            .map(|entry| {
+                Ok((
+                    dictionary_key(&entry.key, remaining)?,
+                    cadence_to_json_value(&entry.value, remaining)?,
+                ))
+            })
+            .collect::<Result<Map<_, _>>>()
+            .map(Value::Object),
+        CadenceValue::Struct { value }
+        | CadenceValue::Resource { value }
+        | CadenceValue::Event { value }
+        | CadenceValue::Contract { value }
+        | CadenceValue::Enum { value } => composite_to_value(value, remaining),
+        CadenceValue::Path { value } => {
+            let domain = match value.domain {
+                PathDomain::Storage => "storage",
+                PathDomain::Private => "private",
+                PathDomain::Public => "public",
+            };
+            Ok(Value::String(format!("/{}/{}", domain, value.identifier)))
+        }
+        CadenceValue::Type { value } => Ok(Value::String(value.static_type.type_id())),
+        CadenceValue::InclusiveRange { value } => {
+            let mut map = Map::with_capacity(3);
+            map.insert(
+                "start".to_string(),
+                cadence_to_json_value(&value.start, remaining)?,
+            );
+            map.insert(
+                "end".to_string(),
+                cadence_to_json_value(&value.end, remaining)?,
+            );
+            map.insert(
+                "step".to_string(),
+                cadence_to_json_value(&value.step, remaining)?,
+            );
+            Ok(Value::Object(map))
+        }
+        CadenceValue::Capability { value } => {
+            let mut map = Map::with_capacity(3);
+            map.insert("id".to_string(), Value::String(value.id.clone()));
+            map.insert("address".to_string(), Value::String(value.address.clone()));
+            map.insert(
+                "borrowType".to_string(),
+                Value::String(value.borrow_type.type_id()),
+            );
+            Ok(Value::Object(map))
+        }
+        CadenceValue::Function { value } => Ok(Value::String(value.function_type.type_id())),
+    }
+}
+
+fn number_to_cadence_value(n: &Number, options: &ConversionOptions) -> Result<CadenceValue> {
+    if n.is_i64() || n.is_u64() {
+        options.number_mapping.integer_value(n.to_string())
+    } else if let Some(f) = n.as_f64() {
+        // Rounds to the 8 decimals of a Fix64, then range-checks the result
+        let rounded = format!("{:.8}", f);
+        if options.number_mapping == NumberMapping::UFix64 {
+            return NumberMapping::UFix64.fixed_point_value(&rounded);
+        }
+        let fixed: Fix64 = rounded.parse().map_err(|_| {
+            Error::InvalidCadenceValue(format!("JSON number {} is out of range for Fix64", n))
+        })?;
+        Ok(fixed.into())
+    } else {
+        Err(Error::InvalidCadenceValue(format!(
+            "Unsupported JSON number: {}",
+            n
+        )))
+    }
+}
+
+// An object is treated as Cadence-JSON when it has a string `type` and a `value`;
+// in strict mode the `type` must also be a known Cadence value type
+fn is_structured_cadence_value(map: &Map<String, Value>, strict: bool) -> bool {
+    match map.get("type") {
+        Some(Value::String(type_)) if map.contains_key("value") => {
+            !strict || CADENCE_VALUE_TYPES.contains(&type_.as_str())
+        }
+        _ => false,
+    }
+}
+
+// Parses an object that is already in Cadence-JSON shape
+fn parse_structured_cadence_value(
+    value: &Value,
+    options: &ConversionOptions,
+    remaining: usize,
+) -> Result<CadenceValue> {
+    // The inner value of an Optional may be either a full Cadence-JSON object
+    // or plain JSON; convert it on its own so it is wrapped exactly once
+    if value.get("type").and_then(Value::as_str) == Some("Optional") {
+        return match value.get("value") {
+            None | Some(Value::Null) => Ok(CadenceValue::Optional { value: None }),
+            Some(inner) => Ok(CadenceValue::Optional {
+                value: Some(Box::new(json_to_cadence_value(inner, options, remaining)?)),
+            }),
+        };
+    }
+
+    check_depth(value, remaining)?;
+    CadenceValue::deserialize(NumericStrings {
+        value,
+        value_field: false,
+    })
+    .map_err(|e| Error::InvalidCadenceValue(format!("Malformed Cadence-JSON object: {}", e)))
+}
+
+// Deserializes a borrowed JSON tree without copying it. Cadence-JSON encodes
+// numbers as strings, but hand-written input often uses JSON numbers for
+// `value` fields, so those are read as their decimal text.
+#[derive(Clone, Copy)]
+struct NumericStrings<'a> {
+    value: &'a Value,
+    // Whether this is the `value` entry of an object
+    value_field: bool,
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for NumericStrings<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for NumericStrings<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.value {
+            Value::Number(n) if self.value_field => visitor.visit_string(n.to_string()),
+            Value::Array(items) => {
+                let mut seq = SeqDeserializer::new(items.iter().map(|value| NumericStrings {
+                    value,
+                    value_field: false,
+                }));
+                let result = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(result)
+            }
+            Value::Object(map) => {
+                let mut entries = MapDeserializer::new(map.iter().map(|(key, value)| {
+                    let entry = NumericStrings {
+                        value,
+                        value_field: key == "value",
+                    };
+                    (BorrowedStrDeserializer::new(key), entry)
+                }));
+                let result = visitor.visit_map(&mut entries)?;
+                entries.end()?;
+                Ok(result)
+            }
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+fn integer_to_value(value: &str) -> Value {
+    if let Ok(n) = value.parse::<i64>() {
+        Value::Number(n.into())
+    } else if let Ok(n) = value.parse::<u64>() {
+        Value::Number(n.into())
+    } else {
+        Value::String(value.to_string())
+    }
+}
+
+// Fixed-point values become JSON numbers only when the f64 rounds back to the
+// same 8 decimals; larger or more precise ones keep their exact decimal text
+fn fixed_point_to_value(value: &str) -> Value {
+    let exact = parse_fixed_point(value);
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|f| exact.is_some() && parse_fixed_point(&format!("{:.8}", f)) == exact)
+        .and_then(Number::from_f64)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(value.to_string()))
+}
+
+// JSON object keys must be strings, so only scalar keys are accepted
+fn dictionary_key(key: &CadenceValue, remaining: usize) -> Result<String> {
+    match cadence_to_json_value(key, remaining)? {
+        Value::String(s) => Ok(s),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        _ => Err(Error::UnsupportedType(format!(
+            "Dictionary key {:?} cannot be used as a JSON object key",
+            key
+        ))),
+    }
+}
+
+fn composite_to_value(value: &CompositeValue, remaining: usize) -> Result<Value> {
+    value
+        .fields
+        .iter()
+        .map(|CompositeField { name, value }| {
+            Ok((name.to_string(), cadence_to_json_value(value, remaining)?))
+        })
+        .collect::<Result<Map<_, _>>>()
+        .map(Value::Object)
+}
+
+// Consumes one level of the nesting budget
+fn descend(remaining: usize) -> Result<usize> {
+    remaining
+        .checked_sub(1)
+        .ok_or_else(|| Error::InvalidCadenceValue("max depth exceeded".to_string()))
+}
+
+// Fails if the JSON nests deeper than the remaining budget; the recursion
+// itself is bounded by that budget
+fn check_depth(value: &Value, remaining: usize) -> Result<()> {
+    match value {
+        Value::Array(items) => {
+            let remaining = descend(remaining)?;
+            items
+                .iter()
+                .try_for_each(|item| check_depth(item, remaining))
+        }
+        Value::Object(map) => {
+            let remaining = descend(remaining)?;
+            map.values()
+                .try_for_each(|item| check_depth(item, remaining))
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,207 @@
+// src/fixed.rs
+
+// This file contains exact fixed-point number types for Cadence's `UFix64`
+// and `Fix64`, stored scaled by 10^8 so arithmetic never goes through floats
+
+use crate::value::{format_fixed_point, parse_fixed_point};
+use crate::{CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An unsigned fixed-point number with 8 decimal places, such as a token
+/// amount, stored as its value multiplied by 10^8.
+///
+/// `"1.5".parse::<UFix64>()` holds `150000000` and displays as `1.50000000`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UFix64(u64);
+
+impl UFix64 {
+    pub const ZERO: UFix64 = UFix64(0);
+    pub const MAX: UFix64 = UFix64(u64::MAX);
+
+    /// Builds a value from its representation scaled by 10^8
+    pub const fn from_scaled(scaled: u64) -> Self {
+        UFix64(scaled)
+    }
+
+    /// Returns the value scaled by 10^8
+    pub const fn scaled(self) -> u64 {
+        self.0
+    }
+
+    /// Adds two values, returning None on overflow
+    pub fn checked_add(self, other: UFix64) -> Option<UFix64> {
+        self.0.checked_add(other.0).map(UFix64)
+    }
+
+    /// Subtracts `other`, returning None if the result would be negative
+    pub fn checked_sub(self, other: UFix64) -> Option<UFix64> {
+        self.0.checked_sub(other.0).map(UFix64)
+    }
+
+    /// Multiplies by an integer, returning None on overflow
+    pub fn checked_mul_u64(self, factor: u64) -> Option<UFix64> {
+        self.0.checked_mul(factor).map(UFix64)
+    }
+}
+
+impl fmt::Display for UFix64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_fixed_point(i128::from(self.0)))
+    }
+}
+
+impl FromStr for UFix64 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let scaled = parse_fixed_point(s)
+            .ok_or_else(|| Error::Custom(format!("Failed to parse UFix64: {}", s)))?;
+        u64::try_from(scaled)
+            .map(UFix64)
+            .map_err(|_| Error::Custom(format!("UFix64 out of range: {}", s)))
+    }
+}
+
+impl ToCadenceValue for UFix64 {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::UFix64 {
+            value: self.to_string(),
+        })
+    }
+}
+
+// Non-negative `Fix64` values are accepted too, like integers of the same
+// signedness are for the primitive integer types
+impl FromCadenceValue for UFix64 {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::UFix64 { value } | CadenceValue::Fix64 { value } => value.parse(),
+            _ => Err(Error::TypeMismatch {
+                expected: "UFix64".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl From<UFix64> for CadenceValue {
+    fn from(value: UFix64) -> Self {
+        CadenceValue::UFix64 {
+            value: value.to_string(),
+        }
+    }
+}
+
+// Serialized as its decimal string, like in Cadence-JSON
+impl Serialize for UFix64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for UFix64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A signed fixed-point number with 8 decimal places, stored as its value
+/// multiplied by 10^8; the signed counterpart of [`UFix64`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fix64(i64);
+
+impl Fix64 {
+    pub const ZERO: Fix64 = Fix64(0);
+    pub const MIN: Fix64 = Fix64(i64::MIN);
+    pub const MAX: Fix64 = Fix64(i64::MAX);
+
+    /// Builds a value from its representation scaled by 10^8
+    pub const fn from_scaled(scaled: i64) -> Self {
+        Fix64(scaled)
+    }
+
+    /// Returns the value scaled by 10^8
+    pub const fn scaled(self) -> i64 {
+        self.0
+    }
+
+    /// Adds two values, returning None on overflow
+    pub fn checked_add(self, other: Fix64) -> Option<Fix64> {
+        self.0.checked_add(other.0).map(Fix64)
+    }
+
+    /// Subtracts `other`, returning None on overflow
+    pub fn checked_sub(self, other: Fix64) -> Option<Fix64> {
+        self.0.checked_sub(other.0).map(Fix64)
+    }
+
+    /// Multiplies by an integer, returning None on overflow
+    pub fn checked_mul_i64(self, factor: i64) -> Option<Fix64> {
+        self.0.checked_mul(factor).map(Fix64)
+    }
+}
+
+impl fmt::Display for Fix64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_fixed_point(i128::from(self.0)))
+    }
+}
+
+impl FromStr for Fix64 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let scaled = parse_fixed_point(s)
+            .ok_or_else(|| Error::Custom(format!("Failed to parse Fix64: {}", s)))?;
+        i64::try_from(scaled)
+            .map(Fix64)
+            .map_err(|_| Error::Custom(format!("Fix64 out of range: {}", s)))
+    }
+}
+
+impl ToCadenceValue for Fix64 {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::Fix64 {
+            value: self.to_string(),
+        })
+    }
+}
+
+// `UFix64` values are accepted as long as they fit the signed range
+impl FromCadenceValue for Fix64 {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Fix64 { value } | CadenceValue::UFix64 { value } => value.parse(),
+            _ => Err(Error::TypeMismatch {
+                expected: "Fix64".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl From<Fix64> for CadenceValue {
+    fn from(value: Fix64) -> Self {
+        CadenceValue::Fix64 {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Serialize for Fix64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Fix64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,193 @@
+// src/flow.rs
+
+// This file contains Rust types for built-in Flow composites, such as the
+// `Account` and `Block` structs returned by scripts
+
+use crate::{
+    Address, Bytes, CadenceValue, CompositeValue, Error, FromCadenceValue, Result, UFix64,
+};
+use alloc::format;
+use alloc::string::ToString;
+
+/// A Flow account as returned by `getAccount`.
+///
+/// Only the standard fields are decoded; any other fields are ignored. The
+/// optional ones are missing from some Cadence versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    pub address: Address,
+    pub balance: UFix64,
+    pub available_balance: Option<UFix64>,
+    pub storage_used: Option<u64>,
+    pub storage_capacity: Option<u64>,
+}
+
+impl FromCadenceValue for Account {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let composite = composite(value, "Account")?;
+        Ok(Account {
+            address: required_field(composite, "address")?,
+            balance: required_field(composite, "balance")?,
+            available_balance: optional_field(composite, "availableBalance")?,
+            storage_used: optional_field(composite, "storageUsed")?,
+            storage_capacity: optional_field(composite, "storageCapacity")?,
+        })
+    }
+}
+
+/// A Flow block as returned by `getCurrentBlock` or `getBlock`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// The 32-byte block ID
+    pub id: Bytes,
+    pub height: u64,
+    pub view: u64,
+    /// Seconds since the Unix epoch
+    pub timestamp: UFix64,
+}
+
+impl FromCadenceValue for Block {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let composite = composite(value, "Block")?;
+        Ok(Block {
+            id: required_field(composite, "id")?,
+            height: required_field(composite, "height")?,
+            view: required_field(composite, "view")?,
+            timestamp: required_field(composite, "timestamp")?,
+        })
+    }
+}
+
+/// A key on a Flow account, as returned by `account.keys.get`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountKey {
+    pub key_index: u32,
+    pub public_key: PublicKey,
+    pub hash_algorithm: HashAlgorithm,
+    /// Signing weight, where `1000.0` alone authorizes a transaction
+    pub weight: UFix64,
+    pub is_revoked: bool,
+}
+
+impl FromCadenceValue for AccountKey {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let composite = composite(value, "AccountKey")?;
+        Ok(AccountKey {
+            key_index: required_field(composite, "keyIndex")?,
+            public_key: required_field(composite, "publicKey")?,
+            hash_algorithm: required_field(composite, "hashAlgorithm")?,
+            weight: required_field(composite, "weight")?,
+            is_revoked: required_field(composite, "isRevoked")?,
+        })
+    }
+}
+
+/// A public key together with the algorithm it verifies signatures with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    /// The raw key bytes, without any encoding prefix
+    pub public_key: Bytes,
+    pub signature_algorithm: SignatureAlgorithm,
+}
+
+impl FromCadenceValue for PublicKey {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let composite = composite(value, "PublicKey")?;
+        Ok(PublicKey {
+            public_key: required_field(composite, "publicKey")?,
+            signature_algorithm: required_field(composite, "signatureAlgorithm")?,
+        })
+    }
+}
+
+/// Cadence's built-in `SignatureAlgorithm` enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignatureAlgorithm {
+    EcdsaP256,
+    EcdsaSecp256k1,
+    BlsBls12381,
+}
+
+impl FromCadenceValue for SignatureAlgorithm {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match enum_raw_value(value, "SignatureAlgorithm")? {
+            1 => Ok(SignatureAlgorithm::EcdsaP256),
+            2 => Ok(SignatureAlgorithm::EcdsaSecp256k1),
+            3 => Ok(SignatureAlgorithm::BlsBls12381),
+            other => Err(unknown_case(other, "SignatureAlgorithm")),
+        }
+    }
+}
+
+/// Cadence's built-in `HashAlgorithm` enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha2_256,
+    Sha2_384,
+    Sha3_256,
+    Sha3_384,
+    Kmac128BlsBls12381,
+    Keccak256,
+}
+
+impl FromCadenceValue for HashAlgorithm {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match enum_raw_value(value, "HashAlgorithm")? {
+            1 => Ok(HashAlgorithm::Sha2_256),
+            2 => Ok(HashAlgorithm::Sha2_384),
+            3 => Ok(HashAlgorithm::Sha3_256),
+            4 => Ok(HashAlgorithm::Sha3_384),
+            5 => Ok(HashAlgorithm::Kmac128BlsBls12381),
+            6 => Ok(HashAlgorithm::Keccak256),
+            other => Err(unknown_case(other, "HashAlgorithm")),
+        }
+    }
+}
+
+// Built-in enums are identified by their UInt8 raw value
+fn enum_raw_value(value: &CadenceValue, expected: &str) -> Result<u8> {
+    match value.enum_raw_value() {
+        Some(raw_value) => u8::from_cadence_value(raw_value).map_err(|e| e.at_field("rawValue")),
+        None => Err(Error::TypeMismatch {
+            expected: expected.to_string(),
+            got: format!("{:?}", value),
+        }),
+    }
+}
+
+fn unknown_case(raw_value: u8, name: &str) -> Error {
+    Error::InvalidCadenceValue(format!("Unknown rawValue {} for enum {}", raw_value, name))
+}
+
+// Built-in composites are usually encoded as structs, but any composite kind
+// with the right fields is accepted
+fn composite<'a>(value: &'a CadenceValue, expected: &str) -> Result<&'a CompositeValue> {
+    match value {
+        CadenceValue::Struct { value }
+        | CadenceValue::Resource { value }
+        | CadenceValue::Event { value }
+        | CadenceValue::Contract { value }
+        | CadenceValue::Enum { value } => Ok(value),
+        _ => Err(Error::TypeMismatch {
+            expected: expected.to_string(),
+            got: format!("{:?}", value),
+        }),
+    }
+}
+
+fn required_field<T: FromCadenceValue>(composite: &CompositeValue, name: &str) -> Result<T> {
+    optional_field(composite, name)?
+        .ok_or_else(|| Error::Custom(format!("Field {} not found in Cadence value", name)))
+}
+
+fn optional_field<T: FromCadenceValue>(
+    composite: &CompositeValue,
+    name: &str,
+) -> Result<Option<T>> {
+    composite
+        .fields
+        .iter()
+        .find(|field| field.name == name)
+        .map(|field| T::from_cadence_value(&field.value).map_err(|e| e.at_field(name)))
+        .transpose()
+}
@@ -0,0 +1,276 @@
+// src/fuzz.rs
+
+// This file contains `arbitrary::Arbitrary` implementations for CadenceValue
+// and its parts, for fuzzing code that consumes Cadence-JSON
+
+use crate::value::format_fixed_point;
+use crate::{
+    CadenceType, CadenceValue, CapabilityValue, CompositeField, CompositeValue, DictionaryEntry,
+    FunctionValue, PathDomain, PathValue, RangeValue, TypeValue,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+// Nesting limit for generated values, so generation always terminates
+const MAX_DEPTH: usize = 4;
+
+// Upper bound on the number of elements in generated collections
+const MAX_LEN: usize = 4;
+
+// Number of variants that don't contain other values; they are listed first
+// in `arbitrary_value` so depth 0 can pick among them only
+const LEAF_VARIANTS: usize = 34;
+const ALL_VARIANTS: usize = LEAF_VARIANTS + 9;
+
+impl<'a> Arbitrary<'a> for CadenceValue {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_value(u, MAX_DEPTH)
+    }
+}
+
+impl<'a> Arbitrary<'a> for DictionaryEntry {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_entry(u, MAX_DEPTH)
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompositeField {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_field(u, MAX_DEPTH)
+    }
+}
+
+impl<'a> Arbitrary<'a> for CompositeValue {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_composite(u, MAX_DEPTH)
+    }
+}
+
+impl<'a> Arbitrary<'a> for PathDomain {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.choose_index(3)? {
+            0 => PathDomain::Storage,
+            1 => PathDomain::Private,
+            _ => PathDomain::Public,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for PathValue {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(PathValue {
+            domain: u.arbitrary()?,
+            identifier: arbitrary_identifier(u)?,
+        })
+    }
+}
+
+fn arbitrary_value(u: &mut Unstructured<'_>, depth: usize) -> Result<CadenceValue> {
+    let variants = if depth == 0 {
+        LEAF_VARIANTS
+    } else {
+        ALL_VARIANTS
+    };
+    let depth = depth.saturating_sub(1);
+    Ok(match u.choose_index(variants)? {
+        0 => CadenceValue::Void {},
+        1 => CadenceValue::Bool {
+            value: u.arbitrary()?,
+        },
+        2 => CadenceValue::String {
+            value: u.arbitrary()?,
+        },
+        3 => CadenceValue::Character {
+            value: u.arbitrary::<char>()?.to_string(),
+        },
+        4 => CadenceValue::Address {
+            value: format!("0x{:016x}", u.arbitrary::<u64>()?),
+        },
+        5 => CadenceValue::Int {
+            value: u.arbitrary::<i128>()?.to_string(),
+        },
+        6 => CadenceValue::Int8 {
+            value: u.arbitrary::<i8>()?.to_string(),
+        },
+        7 => CadenceValue::Int16 {
+            value: u.arbitrary::<i16>()?.to_string(),
+        },
+        8 => CadenceValue::Int32 {
+            value: u.arbitrary::<i32>()?.to_string(),
+        },
+        9 => CadenceValue::Int64 {
+            value: u.arbitrary::<i64>()?.to_string(),
+        },
+        10 => CadenceValue::Int128 {
+            value: u.arbitrary::<i128>()?.to_string(),
+        },
+        11 => CadenceValue::Int256 {
+            value: u.arbitrary::<i128>()?.to_string(),
+        },
+        12 => CadenceValue::UInt {
+            value: u.arbitrary::<u128>()?.to_string(),
+        },
+        13 => CadenceValue::UInt8 {
+            value: u.arbitrary::<u8>()?.to_string(),
+        },
+        14 => CadenceValue::UInt16 {
+            value: u.arbitrary::<u16>()?.to_string(),
+        },
+        15 => CadenceValue::UInt32 {
+            value: u.arbitrary::<u32>()?.to_string(),
+        },
+        16 => CadenceValue::UInt64 {
+            value: u.arbitrary::<u64>()?.to_string(),
+        },
+        17 => CadenceValue::UInt128 {
+            value: u.arbitrary::<u128>()?.to_string(),
+        },
+        18 => CadenceValue::UInt256 {
+            value: u.arbitrary::<u128>()?.to_string(),
+        },
+        19 => CadenceValue::Word8 {
+            value: u.arbitrary::<u8>()?.to_string(),
+        },
+        20 => CadenceValue::Word16 {
+            value: u.arbitrary::<u16>()?.to_string(),
+        },
+        21 => CadenceValue::Word32 {
+            value: u.arbitrary::<u32>()?.to_string(),
+        },
+        22 => CadenceValue::Word64 {
+            value: u.arbitrary::<u64>()?.to_string(),
+        },
+        23 => CadenceValue::Word128 {
+            value: u.arbitrary::<u128>()?.to_string(),
+        },
+        24 => CadenceValue::Word256 {
+            value: u.arbitrary::<u128>()?.to_string(),
+        },
+        25 => CadenceValue::Fix64 {
+            value: format_fixed_point(i128::from(u.arbitrary::<i64>()?)),
+        },
+        26 => CadenceValue::UFix64 {
+            value: format_fixed_point(i128::from(u.arbitrary::<u64>()?)),
+        },
+        27 => CadenceValue::Path {
+            value: u.arbitrary()?,
+        },
+        28 => CadenceValue::Type {
+            value: TypeValue {
+                static_type: arbitrary_simple_type(u)?,
+            },
+        },
+        29 => CadenceValue::Capability {
+            value: CapabilityValue {
+                id: u.arbitrary::<u64>()?.to_string(),
+                address: format!("0x{:016x}", u.arbitrary::<u64>()?),
+                borrow_type: CadenceType::reference(arbitrary_simple_type(u)?),
+            },
+        },
+        30 => CadenceValue::Function {
+            value: FunctionValue {
+                function_type: CadenceType::Function {
+                    type_id: "fun(): Void".to_string(),
+                    parameters: Vec::new(),
+                    purity: None,
+                    return_: Box::new(CadenceType::Void),
+                },
+            },
+        },
+        31 => CadenceValue::Optional { value: None },
+        32 => CadenceValue::Array { value: Vec::new() },
+        33 => CadenceValue::Dictionary { value: Vec::new() },
+        34 => CadenceValue::Optional {
+            value: Some(Box::new(arbitrary_value(u, depth)?)),
+        },
+        35 => CadenceValue::Array {
+            value: arbitrary_vec(u, |u| arbitrary_value(u, depth))?,
+        },
+        36 => CadenceValue::Dictionary {
+            value: arbitrary_vec(u, |u| arbitrary_entry(u, depth))?,
+        },
+        37 => CadenceValue::Struct {
+            value: arbitrary_composite(u, depth)?,
+        },
+        38 => CadenceValue::Resource {
+            value: arbitrary_composite(u, depth)?,
+        },
+        39 => CadenceValue::Event {
+            value: arbitrary_composite(u, depth)?,
+        },
+        40 => CadenceValue::Contract {
+            value: arbitrary_composite(u, depth)?,
+        },
+        41 => CadenceValue::Enum {
+            value: arbitrary_composite(u, depth)?,
+        },
+        _ => CadenceValue::InclusiveRange {
+            value: RangeValue {
+                start: Box::new(arbitrary_value(u, depth)?),
+                end: Box::new(arbitrary_value(u, depth)?),
+                step: Box::new(arbitrary_value(u, depth)?),
+            },
+        },
+    })
+}
+
+fn arbitrary_entry(u: &mut Unstructured<'_>, depth: usize) -> Result<DictionaryEntry> {
+    Ok(DictionaryEntry {
+        key: arbitrary_value(u, 0)?,
+        value: arbitrary_value(u, depth)?,
+    })
+}
+
+fn arbitrary_field(u: &mut Unstructured<'_>, depth: usize) -> Result<CompositeField> {
+    Ok(CompositeField {
+        name: arbitrary_identifier(u)?.as_str().into(),
+        value: arbitrary_value(u, depth)?,
+    })
+}
+
+fn arbitrary_composite(u: &mut Unstructured<'_>, depth: usize) -> Result<CompositeValue> {
+    let id = format!(
+        "A.{:016x}.{}",
+        u.arbitrary::<u64>()?,
+        arbitrary_identifier(u)?
+    );
+    Ok(CompositeValue {
+        id: id.as_str().into(),
+        fields: arbitrary_vec(u, |u| arbitrary_field(u, depth))?,
+    })
+}
+
+fn arbitrary_vec<'a, T>(
+    u: &mut Unstructured<'a>,
+    mut element: impl FnMut(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    let len = u.int_in_range(0..=MAX_LEN)?;
+    let mut values = Vec::with_capacity(len);
+    for _ in 0..len {
+        values.push(element(u)?);
+    }
+    Ok(values)
+}
+
+// A non-empty ASCII identifier, as used for field names and path identifiers
+fn arbitrary_identifier(u: &mut Unstructured<'_>) -> Result<String> {
+    const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_";
+    let len = u.int_in_range(1..=8)?;
+    let mut identifier = String::with_capacity(len);
+    for _ in 0..len {
+        identifier.push(*u.choose(CHARS)? as char);
+    }
+    Ok(identifier)
+}
+
+fn arbitrary_simple_type(u: &mut Unstructured<'_>) -> Result<CadenceType> {
+    Ok(match u.choose_index(8)? {
+        0 => CadenceType::Bool,
+        1 => CadenceType::String,
+        2 => CadenceType::Address,
+        3 => CadenceType::Int,
+        4 => CadenceType::UInt64,
+        5 => CadenceType::UFix64,
+        6 => CadenceType::AnyStruct,
+        _ => CadenceType::AnyResource,
+    })
+}
@@ -3,9 +3,31 @@
 // This file contains implementations of ToCadenceValue and FromCadenceValue
 // for standard Rust types
 
-use crate::{CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue};
-use std::collections::{BTreeMap, HashMap};
+use crate::value::FIXED_POINT_SCALE;
+use crate::{
+    Account, AccountKey, Address, Block, CadenceValue, CapabilityValue, CompositeField,
+    CompositeValue, Error, FromCadenceValue, PathValue, PublicKey, Result, ToCadenceValue, UFix64,
+};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::num::{
+    IntErrorKind, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU8, NonZeroU16, NonZeroU32,
+    NonZeroU64, ParseIntError,
+};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
+// The identity impls let containers hold values of mixed types: an
+// `[AnyStruct]` array such as `["a", 1, true]` decodes as `Vec<CadenceValue>`
+// through the generic `Vec<T>` impl, which a dedicated impl would conflict with
 impl FromCadenceValue for CadenceValue {
     fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
         Ok(value.clone())
@@ -35,7 +57,21 @@ impl ToCadenceValue for str {
     }
 }
 
-impl ToCadenceValue for &str {
+// Only `String` values decode into a String; use `Lenient<String>` to also
+// accept addresses and numbers
+impl FromCadenceValue for String {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::String { value } => Ok(value.clone()),
+            _ => Err(Error::TypeMismatch {
+                expected: "String".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl ToCadenceValue for Cow<'_, str> {
     fn to_cadence_value(&self) -> Result<CadenceValue> {
         Ok(CadenceValue::String {
             value: self.to_string(),
@@ -43,13 +79,37 @@ impl ToCadenceValue for &str {
     }
 }
 
-impl FromCadenceValue for String {
+impl FromCadenceValue for Cow<'static, str> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        Ok(Cow::Owned(String::from_cadence_value(value)?))
+    }
+}
+
+// Character implementations. A Cadence Character is a grapheme cluster, so
+// only characters made of a single code point fit in a `char`
+impl ToCadenceValue for char {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::Character {
+            value: self.to_string(),
+        })
+    }
+}
+
+impl FromCadenceValue for char {
     fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
         match value {
-            CadenceValue::String { value } => Ok(value.clone()),
-            CadenceValue::Address { value } => Ok(value.clone()),
+            CadenceValue::Character { value } => {
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(c),
+                    _ => Err(Error::InvalidCadenceValue(format!(
+                        "Character {:?} is not a single char",
+                        value
+                    ))),
+                }
+            }
             _ => Err(Error::TypeMismatch {
-                expected: "String".to_string(),
+                expected: "Character".to_string(),
                 got: format!("{:?}", value),
             }),
         }
@@ -75,47 +135,160 @@ impl FromCadenceValue for bool {
     }
 }
 
-// Integer implementations
+// Unit implementations
+impl ToCadenceValue for () {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::Void {})
+    }
+}
+
+impl FromCadenceValue for () {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Void {} => Ok(()),
+            _ => Err(Error::TypeMismatch {
+                expected: "Void".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+// PhantomData carries no data, so it is encoded like `()`. Derived impls skip
+// PhantomData fields altogether.
+impl<T: ?Sized + Sync> ToCadenceValue for PhantomData<T> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::Void {})
+    }
+}
+
+impl<T: ?Sized + Sync> FromCadenceValue for PhantomData<T> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        <()>::from_cadence_value(value).map(|()| PhantomData)
+    }
+}
+
+// Integer implementations. Decoding also accepts `Int`, `UInt` and every
+// other integer variant of the same signedness, including the `Word*` types
+// for unsigned integers, since Flow may widen types; out-of-range values fail
+// to parse.
+// `$pinned` names the `EncodeOptions` field that can override `$variant`
 macro_rules! impl_int_to_cadence {
-    ($t:ty, $variant:ident) => {
+    ($t:ty, $variant:ident, $same_signedness:ident, $pinned:ident) => {
         impl ToCadenceValue for $t {
             fn to_cadence_value(&self) -> Result<CadenceValue> {
-                Ok(CadenceValue::$variant {
-                    value: self.to_string(),
-                })
+                let value = self.to_string();
+                #[cfg(feature = "std")]
+                if let Some(mapping) = crate::EncodeOptions::current().$pinned {
+                    return mapping.integer_value(value);
+                }
+                Ok(CadenceValue::$variant { value })
             }
         }
 
         impl FromCadenceValue for $t {
             fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
-                match value {
-                    CadenceValue::$variant { value } => value.parse().map_err(|e| {
-                        Error::Custom(format!("Failed to parse {}: {}", stringify!($t), e))
-                    }),
-                    CadenceValue::Int { value } => value.parse().map_err(|e| {
-                        Error::Custom(format!("Failed to parse {}: {}", stringify!($t), e))
-                    }),
-                    CadenceValue::UInt { value } => value.parse().map_err(|e| {
-                        Error::Custom(format!("Failed to parse {}: {}", stringify!($t), e))
-                    }),
-                    _ => Err(Error::TypeMismatch {
+                let digits = match value {
+                    CadenceValue::$variant { value }
+                    | CadenceValue::Int { value }
+                    | CadenceValue::UInt { value } => value,
+                    _ => $same_signedness(value).ok_or_else(|| Error::TypeMismatch {
                         expected: stringify!($variant).to_string(),
                         got: format!("{:?}", value),
-                    }),
-                }
+                    })?,
+                };
+                parse_integer(digits, stringify!($variant))
+            }
+        }
+    };
+}
+
+impl_int_to_cadence!(u8, UInt8, unsigned_digits, unsigned);
+impl_int_to_cadence!(u16, UInt16, unsigned_digits, unsigned);
+impl_int_to_cadence!(u32, UInt32, unsigned_digits, unsigned);
+impl_int_to_cadence!(u64, UInt64, unsigned_digits, unsigned);
+impl_int_to_cadence!(i8, Int8, signed_digits, signed);
+impl_int_to_cadence!(i16, Int16, signed_digits, signed);
+impl_int_to_cadence!(i32, Int32, signed_digits, signed);
+impl_int_to_cadence!(i64, Int64, signed_digits, signed);
+
+// Parses integer digits, telling values that don't fit the target type apart
+// from strings that aren't integers at all
+fn parse_integer<T>(digits: &str, type_name: &str) -> Result<T>
+where
+    T: core::str::FromStr<Err = ParseIntError>,
+{
+    digits.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+            Error::InvalidCadenceValue(format!("value {} out of range for {}", digits, type_name))
+        }
+        _ => Error::InvalidCadenceValue(format!("{:?} is not a valid integer", digits)),
+    })
+}
+
+// The digits of any unsigned integer variant
+fn unsigned_digits(value: &CadenceValue) -> Option<&String> {
+    match value {
+        CadenceValue::UInt8 { value }
+        | CadenceValue::UInt16 { value }
+        | CadenceValue::UInt32 { value }
+        | CadenceValue::UInt64 { value }
+        | CadenceValue::UInt128 { value }
+        | CadenceValue::UInt256 { value }
+        | CadenceValue::Word8 { value }
+        | CadenceValue::Word16 { value }
+        | CadenceValue::Word32 { value }
+        | CadenceValue::Word64 { value }
+        | CadenceValue::Word128 { value }
+        | CadenceValue::Word256 { value } => Some(value),
+        _ => None,
+    }
+}
+
+// The digits of any signed integer variant
+fn signed_digits(value: &CadenceValue) -> Option<&String> {
+    match value {
+        CadenceValue::Int8 { value }
+        | CadenceValue::Int16 { value }
+        | CadenceValue::Int32 { value }
+        | CadenceValue::Int64 { value }
+        | CadenceValue::Int128 { value }
+        | CadenceValue::Int256 { value } => Some(value),
+        _ => None,
+    }
+}
+
+// NonZero integer implementations
+macro_rules! impl_nonzero_to_cadence {
+    ($t:ty, $inner:ty) => {
+        impl ToCadenceValue for $t {
+            fn to_cadence_value(&self) -> Result<CadenceValue> {
+                self.get().to_cadence_value()
+            }
+        }
+
+        impl FromCadenceValue for $t {
+            fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+                let inner = <$inner>::from_cadence_value(value)?;
+                <$t>::new(inner).ok_or_else(|| {
+                    Error::InvalidCadenceValue(format!(
+                        "Expected a non-zero value for {}",
+                        stringify!($t)
+                    ))
+                })
             }
         }
     };
 }
 
-impl_int_to_cadence!(u8, UInt8);
-impl_int_to_cadence!(u16, UInt16);
-impl_int_to_cadence!(u32, UInt32);
-impl_int_to_cadence!(u64, UInt64);
-impl_int_to_cadence!(i8, Int8);
-impl_int_to_cadence!(i16, Int16);
-impl_int_to_cadence!(i32, Int32);
-impl_int_to_cadence!(i64, Int64);
+impl_nonzero_to_cadence!(NonZeroU8, u8);
+impl_nonzero_to_cadence!(NonZeroU16, u16);
+impl_nonzero_to_cadence!(NonZeroU32, u32);
+impl_nonzero_to_cadence!(NonZeroU64, u64);
+impl_nonzero_to_cadence!(NonZeroI8, i8);
+impl_nonzero_to_cadence!(NonZeroI16, i16);
+impl_nonzero_to_cadence!(NonZeroI32, i32);
+impl_nonzero_to_cadence!(NonZeroI64, i64);
 
 // Float implementations
 impl ToCadenceValue for f32 {
@@ -168,6 +341,128 @@ impl FromCadenceValue for f64 {
     }
 }
 
+// Path and capability implementations, for decoding the payloads of those
+// values on their own
+impl ToCadenceValue for PathValue {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::Path {
+            value: self.clone(),
+        })
+    }
+}
+
+impl FromCadenceValue for PathValue {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Path { value } => Ok(value.clone()),
+            _ => Err(Error::TypeMismatch {
+                expected: "Path".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl ToCadenceValue for CapabilityValue {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::Capability {
+            value: self.clone(),
+        })
+    }
+}
+
+impl FromCadenceValue for CapabilityValue {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Capability { value } => Ok(value.clone()),
+            _ => Err(Error::TypeMismatch {
+                expected: "Capability".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+// TryFrom implementations for primitives and helper structs, delegating to
+// FromCadenceValue, both by value and by reference
+macro_rules! impl_try_from_cadence {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<CadenceValue> for $t {
+                type Error = Error;
+
+                fn try_from(value: CadenceValue) -> Result<Self> {
+                    <$t>::from_cadence_value(&value)
+                }
+            }
+
+            impl TryFrom<&CadenceValue> for $t {
+                type Error = Error;
+
+                fn try_from(value: &CadenceValue) -> Result<Self> {
+                    <$t>::from_cadence_value(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_cadence!(
+    String, char, bool, u8, u16, u32, u64, i8, i16, i32, i64, f32, f64
+);
+impl_try_from_cadence!(Account, Block, PathValue, CapabilityValue);
+impl_try_from_cadence!(Address, AccountKey, PublicKey);
+
+// From implementations for conversions that cannot fail
+impl From<bool> for CadenceValue {
+    fn from(value: bool) -> Self {
+        CadenceValue::Bool { value }
+    }
+}
+
+impl From<String> for CadenceValue {
+    fn from(value: String) -> Self {
+        CadenceValue::String { value }
+    }
+}
+
+impl From<char> for CadenceValue {
+    fn from(value: char) -> Self {
+        CadenceValue::Character {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl From<&str> for CadenceValue {
+    fn from(value: &str) -> Self {
+        CadenceValue::String {
+            value: value.to_string(),
+        }
+    }
+}
+
+macro_rules! impl_int_from {
+    ($t:ty, $variant:ident) => {
+        impl From<$t> for CadenceValue {
+            fn from(value: $t) -> Self {
+                CadenceValue::$variant {
+                    value: value.to_string(),
+                }
+            }
+        }
+    };
+}
+
+impl_int_from!(u8, UInt8);
+impl_int_from!(u16, UInt16);
+impl_int_from!(u32, UInt32);
+impl_int_from!(u64, UInt64);
+impl_int_from!(i8, Int8);
+impl_int_from!(i16, Int16);
+impl_int_from!(i32, Int32);
+impl_int_from!(i64, Int64);
+
 // Vec implementations
 impl<T: ToCadenceValue> ToCadenceValue for Vec<T> {
     fn to_cadence_value(&self) -> Result<CadenceValue> {
@@ -184,8 +479,8 @@ impl<T: FromCadenceValue> FromCadenceValue for Vec<T> {
         match value {
             CadenceValue::Array { value } => {
                 let mut result = Vec::with_capacity(value.len());
-                for item in value {
-                    result.push(T::from_cadence_value(item)?);
+                for (index, item) in value.iter().enumerate() {
+                    result.push(T::from_cadence_value(item).map_err(|e| e.at_index(index))?);
                 }
                 Ok(result)
             }
@@ -197,7 +492,89 @@ impl<T: FromCadenceValue> FromCadenceValue for Vec<T> {
     }
 }
 
+// Fixed-size array implementations, matching Cadence constant-sized arrays
+impl<T: ToCadenceValue, const N: usize> ToCadenceValue for [T; N] {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        let mut values = Vec::with_capacity(N);
+        for item in self {
+            values.push(item.to_cadence_value()?);
+        }
+        Ok(CadenceValue::Array { value: values })
+    }
+}
+
+impl<T: FromCadenceValue, const N: usize> FromCadenceValue for [T; N] {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Array { value } => {
+                if value.len() != N {
+                    return Err(Error::InvalidCadenceValue(format!(
+                        "Expected array of length {}, got {}",
+                        N,
+                        value.len()
+                    )));
+                }
+                let mut result = Vec::with_capacity(N);
+                for (index, item) in value.iter().enumerate() {
+                    result.push(T::from_cadence_value(item).map_err(|e| e.at_index(index))?);
+                }
+                result.try_into().map_err(|_| {
+                    Error::InvalidCadenceValue(format!("Expected array of length {}", N))
+                })
+            }
+            _ => Err(Error::TypeMismatch {
+                expected: "Array".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+// Reference and smart pointer implementations
+//
+// Pointers are transparent: they encode exactly like the value they wrap.
+// `Rc<T>` is not covered since the traits require `Sync`.
+impl<T: ToCadenceValue + ?Sized> ToCadenceValue for &T {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        (**self).to_cadence_value()
+    }
+}
+
+impl<T: ToCadenceValue + ?Sized> ToCadenceValue for &mut T {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        (**self).to_cadence_value()
+    }
+}
+
+impl<T: ToCadenceValue + ?Sized> ToCadenceValue for Box<T> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        (**self).to_cadence_value()
+    }
+}
+
+impl<T: FromCadenceValue> FromCadenceValue for Box<T> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        Ok(Box::new(T::from_cadence_value(value)?))
+    }
+}
+
+impl<T: ToCadenceValue + Send + ?Sized> ToCadenceValue for Arc<T> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        (**self).to_cadence_value()
+    }
+}
+
+impl<T: FromCadenceValue + Send> FromCadenceValue for Arc<T> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        Ok(Arc::new(T::from_cadence_value(value)?))
+    }
+}
+
 // Option implementations
+//
+// Every `Option` layer maps to exactly one `Optional` layer, so nested options
+// keep their shape: `None` is `Optional(nil)`, while `Some(None)` is
+// `Optional(Optional(nil))`. The inner layer is never flattened into the outer one.
 impl<T: ToCadenceValue> ToCadenceValue for Option<T> {
     fn to_cadence_value(&self) -> Result<CadenceValue> {
         match self {
@@ -227,7 +604,73 @@ impl<T: FromCadenceValue> FromCadenceValue for Option<T> {
     }
 }
 
+// Result implementations
+//
+// A `Result` is encoded like a derived enum: an `Enum` named `Result` whose
+// `rawValue` is 0 for `Ok` and 1 for `Err`, with the payload in field `0`.
+impl<T: ToCadenceValue, E: ToCadenceValue> ToCadenceValue for core::result::Result<T, E> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        let (raw_value, payload) = match self {
+            Ok(value) => (0u8, value.to_cadence_value()?),
+            Err(error) => (1u8, error.to_cadence_value()?),
+        };
+        Ok(CadenceValue::Enum {
+            value: CompositeValue {
+                id: "Result".into(),
+                fields: vec![
+                    CompositeField {
+                        name: "rawValue".into(),
+                        value: raw_value.into(),
+                    },
+                    CompositeField {
+                        name: "0".into(),
+                        value: payload,
+                    },
+                ],
+            },
+        })
+    }
+}
+
+impl<T: FromCadenceValue, E: FromCadenceValue> FromCadenceValue for core::result::Result<T, E> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let composite = match value {
+            CadenceValue::Enum { value } => value,
+            _ => {
+                return Err(Error::TypeMismatch {
+                    expected: "Enum".to_string(),
+                    got: format!("{:?}", value),
+                });
+            }
+        };
+        let field = |name: &str| {
+            composite
+                .fields
+                .iter()
+                .find(|field| field.name == name)
+                .map(|field| &field.value)
+                .ok_or_else(|| Error::Custom(format!("Field {} not found in Cadence value", name)))
+        };
+
+        let raw_value =
+            u8::from_cadence_value(field("rawValue")?).map_err(|e| e.at_field("rawValue"))?;
+        match raw_value {
+            0 => Ok(Ok(
+                T::from_cadence_value(field("0")?).map_err(|e| e.at_field("0"))?
+            )),
+            1 => Ok(Err(
+                E::from_cadence_value(field("0")?).map_err(|e| e.at_field("0"))?
+            )),
+            other => Err(Error::Custom(format!(
+                "Unknown rawValue {} for enum Result",
+                other
+            ))),
+        }
+    }
+}
+
 // HashMap implementations
+#[cfg(feature = "std")]
 impl<K, V> ToCadenceValue for HashMap<K, V>
 where
     K: ToCadenceValue,
@@ -245,18 +688,20 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<K, V> FromCadenceValue for HashMap<K, V>
 where
-    K: FromCadenceValue + Eq + std::hash::Hash,
+    K: FromCadenceValue + Eq + core::hash::Hash,
     V: FromCadenceValue,
 {
     fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
         match value {
             CadenceValue::Dictionary { value } => {
                 let mut result = HashMap::with_capacity(value.len());
-                for entry in value {
-                    let key = K::from_cadence_value(&entry.key)?;
-                    let value = V::from_cadence_value(&entry.value)?;
+                for (index, entry) in value.iter().enumerate() {
+                    let key = K::from_cadence_value(&entry.key).map_err(|e| e.at_index(index))?;
+                    let value =
+                        V::from_cadence_value(&entry.value).map_err(|e| e.at_index(index))?;
                     result.insert(key, value);
                 }
                 Ok(result)
@@ -296,9 +741,10 @@ where
         match value {
             CadenceValue::Dictionary { value } => {
                 let mut result = BTreeMap::new();
-                for entry in value {
-                    let key = K::from_cadence_value(&entry.key)?;
-                    let value = V::from_cadence_value(&entry.value)?;
+                for (index, entry) in value.iter().enumerate() {
+                    let key = K::from_cadence_value(&entry.key).map_err(|e| e.at_index(index))?;
+                    let value =
+                        V::from_cadence_value(&entry.value).map_err(|e| e.at_index(index))?;
                     result.insert(key, value);
                 }
                 Ok(result)
@@ -311,45 +757,201 @@ where
     }
 }
 
-// Tuple implementations (for common sizes)
-impl<T1, T2> ToCadenceValue for (T1, T2)
+// IndexMap implementations, which keep the dictionary's entry order in both
+// directions
+#[cfg(feature = "indexmap")]
+impl<K, V> ToCadenceValue for indexmap::IndexMap<K, V>
 where
-    T1: ToCadenceValue,
-    T2: ToCadenceValue,
+    K: ToCadenceValue,
+    V: ToCadenceValue,
 {
     fn to_cadence_value(&self) -> Result<CadenceValue> {
-        let mut values = Vec::with_capacity(2);
-        values.push(self.0.to_cadence_value()?);
-        values.push(self.1.to_cadence_value()?);
-        Ok(CadenceValue::Array { value: values })
+        let mut entries = Vec::with_capacity(self.len());
+        for (key, value) in self {
+            entries.push(crate::DictionaryEntry {
+                key: key.to_cadence_value()?,
+                value: value.to_cadence_value()?,
+            });
+        }
+        Ok(CadenceValue::Dictionary { value: entries })
     }
 }
 
-impl<T1, T2> FromCadenceValue for (T1, T2)
+#[cfg(feature = "indexmap")]
+impl<K, V> FromCadenceValue for indexmap::IndexMap<K, V>
 where
-    T1: FromCadenceValue,
-    T2: FromCadenceValue,
+    K: FromCadenceValue + Eq + core::hash::Hash,
+    V: FromCadenceValue,
 {
     fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
         match value {
-            CadenceValue::Array { value } => {
-                if value.len() != 2 {
-                    return Err(Error::Custom(format!(
-                        "Expected array of length 2 for tuple, got {}",
-                        value.len()
-                    )));
+            CadenceValue::Dictionary { value } => {
+                let mut result = indexmap::IndexMap::with_capacity(value.len());
+                for (index, entry) in value.iter().enumerate() {
+                    let key = K::from_cadence_value(&entry.key).map_err(|e| e.at_index(index))?;
+                    let value =
+                        V::from_cadence_value(&entry.value).map_err(|e| e.at_index(index))?;
+                    result.insert(key, value);
                 }
-                Ok((
-                    T1::from_cadence_value(&value[0])?,
-                    T2::from_cadence_value(&value[1])?,
-                ))
+                Ok(result)
             }
             _ => Err(Error::TypeMismatch {
-                expected: "Array".to_string(),
+                expected: "Dictionary".to_string(),
                 got: format!("{:?}", value),
             }),
         }
     }
 }
 
-// Add more tuple implementations as needed for (T1, T2, T3), etc.
+// Tuple implementations (for common sizes)
+//
+// Tuples are encoded as arrays with one element per tuple field.
+fn check_tuple_length(values: &[CadenceValue], arity: usize) -> Result<()> {
+    if values.len() != arity {
+        return Err(Error::Custom(format!(
+            "Expected array of length {} for {}-tuple, got {}",
+            arity,
+            arity,
+            values.len()
+        )));
+    }
+    Ok(())
+}
+
+macro_rules! impl_tuple_cadence {
+    ($arity:expr => $($name:ident $index:tt),+) => {
+        impl<$($name),+> ToCadenceValue for ($($name,)+)
+        where
+            $($name: ToCadenceValue,)+
+        {
+            fn to_cadence_value(&self) -> Result<CadenceValue> {
+                let values = vec![$(self.$index.to_cadence_value()?),+];
+                Ok(CadenceValue::Array { value: values })
+            }
+        }
+
+        impl<$($name),+> FromCadenceValue for ($($name,)+)
+        where
+            $($name: FromCadenceValue,)+
+        {
+            fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+                match value {
+                    CadenceValue::Array { value } => {
+                        check_tuple_length(value, $arity)?;
+                        Ok(($(
+                            $name::from_cadence_value(&value[$index])
+                                .map_err(|e| e.at_index($index))?,
+                        )+))
+                    }
+                    _ => Err(Error::TypeMismatch {
+                        expected: "Array".to_string(),
+                        got: format!("{:?}", value),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_tuple_cadence!(2 => T1 0, T2 1);
+impl_tuple_cadence!(3 => T1 0, T2 1, T3 2);
+impl_tuple_cadence!(4 => T1 0, T2 1, T3 2, T4 3);
+impl_tuple_cadence!(5 => T1 0, T2 1, T3 2, T4 3, T5 4);
+impl_tuple_cadence!(6 => T1 0, T2 1, T3 2, T4 3, T5 4, T6 5);
+
+// Duration implementations
+//
+// Durations are encoded as UFix64 seconds. Cadence only has 8 decimals, so
+// the sub-second part is truncated to multiples of 10 nanoseconds.
+impl ToCadenceValue for Duration {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        let scaled = self
+            .as_secs()
+            .checked_mul(FIXED_POINT_SCALE as u64)
+            .and_then(|scaled| scaled.checked_add(u64::from(self.subsec_nanos() / 10)))
+            .ok_or_else(|| {
+                Error::InvalidCadenceValue(format!("Duration {:?} is too long for UFix64", self))
+            })?;
+        UFix64::from_scaled(scaled).to_cadence_value()
+    }
+}
+
+impl FromCadenceValue for Duration {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let scaled = UFix64::from_cadence_value(value)?.scaled();
+        let scale = FIXED_POINT_SCALE as u64;
+        Ok(Duration::new(scaled / scale, (scaled % scale) as u32 * 10))
+    }
+}
+
+// Timestamp implementations
+//
+// Flow represents timestamps as UFix64 seconds since the Unix epoch, which
+// keeps sub-second precision down to 10 nanoseconds.
+#[cfg(feature = "chrono")]
+impl ToCadenceValue for chrono::DateTime<chrono::Utc> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        let scaled = i128::from(self.timestamp()) * FIXED_POINT_SCALE
+            + i128::from(self.timestamp_subsec_nanos() / 10);
+        if scaled < 0 {
+            return Err(Error::InvalidCadenceValue(format!(
+                "Timestamp {} is before the Unix epoch and cannot be a UFix64",
+                self
+            )));
+        }
+        if scaled > i128::from(u64::MAX) {
+            return Err(Error::InvalidCadenceValue(format!(
+                "Timestamp {} is past the largest UFix64",
+                self
+            )));
+        }
+        CadenceValue::fixed_point_from_scaled(scaled, &crate::CadenceType::UFix64)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromCadenceValue for chrono::DateTime<chrono::Utc> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        let scaled = match value {
+            CadenceValue::UFix64 { .. } => value
+                .as_fixed_point()
+                .filter(|scaled| *scaled >= 0)
+                .ok_or_else(|| Error::Custom(format!("Failed to parse timestamp: {:?}", value)))?,
+            _ => {
+                return Err(Error::TypeMismatch {
+                    expected: "UFix64".to_string(),
+                    got: format!("{:?}", value),
+                });
+            }
+        };
+        let seconds = i64::try_from(scaled / FIXED_POINT_SCALE)
+            .map_err(|e| Error::Custom(format!("Failed to parse timestamp: {}", e)))?;
+        let nanos = (scaled % FIXED_POINT_SCALE) as u32 * 10;
+        chrono::DateTime::from_timestamp(seconds, nanos)
+            .ok_or_else(|| Error::Custom(format!("Timestamp out of range: {:?}", value)))
+    }
+}
+
+// UUID implementations, encoded as hyphenated strings
+#[cfg(feature = "uuid")]
+impl ToCadenceValue for uuid::Uuid {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        Ok(CadenceValue::String {
+            value: self.hyphenated().to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl FromCadenceValue for uuid::Uuid {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::String { value } => uuid::Uuid::parse_str(value)
+                .map_err(|e| Error::Custom(format!("Failed to parse Uuid: {}", e))),
+            _ => Err(Error::TypeMismatch {
+                expected: "String".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// src/infer.rs
+
+// This file contains best-effort inference of a CadenceType from a decoded
+// CadenceValue, e.g. to build the type of a matching script argument
+
+use crate::{CadenceType, CadenceValue, CompositeValue, FieldType, PathDomain};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+impl CadenceValue {
+    /// Infers the type of this value.
+    ///
+    /// Leaf values map to their own type and containers are inferred from their
+    /// contents: arrays become `VariableSizedArray`s of the unified element
+    /// type, dictionaries unify their keys and values, and composites keep their
+    /// type id and field types with no initializers. Elements of different
+    /// types unify to `AnyStruct` (or `AnyResource` if they're all resources),
+    /// so heterogeneous arrays infer `[AnyStruct]`. Empty containers and `nil`
+    /// infer `Never` as their inner type.
+    pub fn infer_type(&self) -> CadenceType {
+        match self {
+            CadenceValue::Void {} => CadenceType::Void,
+            CadenceValue::Optional { value } => CadenceType::Optional {
+                type_: Box::new(match value {
+                    Some(inner) => inner.infer_type(),
+                    None => CadenceType::Never,
+                }),
+            },
+            CadenceValue::Bool { .. } => CadenceType::Bool,
+            CadenceValue::String { .. } => CadenceType::String,
+            CadenceValue::Character { .. } => CadenceType::Character,
+            CadenceValue::Address { .. } => CadenceType::Address,
+            CadenceValue::Int { .. } => CadenceType::Int,
+            CadenceValue::Int8 { .. } => CadenceType::Int8,
+            CadenceValue::Int16 { .. } => CadenceType::Int16,
+            CadenceValue::Int32 { .. } => CadenceType::Int32,
+            CadenceValue::Int64 { .. } => CadenceType::Int64,
+            CadenceValue::Int128 { .. } => CadenceType::Int128,
+            CadenceValue::Int256 { .. } => CadenceType::Int256,
+            CadenceValue::UInt { .. } => CadenceType::UInt,
+            CadenceValue::UInt8 { .. } => CadenceType::UInt8,
+            CadenceValue::UInt16 { .. } => CadenceType::UInt16,
+            CadenceValue::UInt32 { .. } => CadenceType::UInt32,
+            CadenceValue::UInt64 { .. } => CadenceType::UInt64,
+            CadenceValue::UInt128 { .. } => CadenceType::UInt128,
+            CadenceValue::UInt256 { .. } => CadenceType::UInt256,
+            CadenceValue::Word8 { .. } => CadenceType::Word8,
+            CadenceValue::Word16 { .. } => CadenceType::Word16,
+            CadenceValue::Word32 { .. } => CadenceType::Word32,
+            CadenceValue::Word64 { .. } => CadenceType::Word64,
+            CadenceValue::Word128 { .. } => CadenceType::Word128,
+            CadenceValue::Word256 { .. } => CadenceType::Word256,
+            CadenceValue::Fix64 { .. } => CadenceType::Fix64,
+            CadenceValue::UFix64 { .. } => CadenceType::UFix64,
+            CadenceValue::Array { value } => CadenceType::VariableSizedArray {
+                type_: Box::new(unify_all(value.iter().map(CadenceValue::infer_type))),
+            },
+            CadenceValue::Dictionary { value } => CadenceType::Dictionary {
+                key: Box::new(unify_all(value.iter().map(|entry| entry.key.infer_type()))),
+                value: Box::new(unify_all(
+                    value.iter().map(|entry| entry.value.infer_type()),
+                )),
+            },
+            CadenceValue::Struct { value } => CadenceType::Struct {
+                type_: String::new(),
+                type_id: value.id.to_string(),
+                initializers: Vec::new(),
+                fields: field_types(value),
+            },
+            CadenceValue::Resource { value } => CadenceType::Resource {
+                type_: String::new(),
+                type_id: value.id.to_string(),
+                initializers: Vec::new(),
+                fields: field_types(value),
+            },
+            CadenceValue::Event { value } => CadenceType::Event {
+                type_: String::new(),
+                type_id: value.id.to_string(),
+                initializers: Vec::new(),
+                fields: field_types(value),
+            },
+            CadenceValue::Contract { value } => CadenceType::Contract {
+                type_: String::new(),
+                type_id: value.id.to_string(),
+                initializers: Vec::new(),
+                fields: field_types(value),
+            },
+            // The raw type of an enum is the type of its `rawValue` field
+            CadenceValue::Enum { value } => CadenceType::Enum {
+                type_: Box::new(
+                    value
+                        .fields
+                        .iter()
+                        .find(|field| field.name == "rawValue")
+                        .map_or(CadenceType::UInt8, |field| field.value.infer_type()),
+                ),
+                type_id: value.id.to_string(),
+                initializers: Vec::new(),
+                fields: field_types(value),
+            },
+            CadenceValue::Path { value } => match value.domain {
+                PathDomain::Storage => CadenceType::StoragePath,
+                PathDomain::Public => CadenceType::PublicPath,
+                PathDomain::Private => CadenceType::PrivatePath,
+            },
+            CadenceValue::Type { .. } => CadenceType::Type,
+            CadenceValue::InclusiveRange { value } => CadenceType::InclusiveRange {
+                element: Box::new(unify_all(
+                    [&value.start, &value.end, &value.step]
+                        .into_iter()
+                        .map(|bound| bound.infer_type()),
+                )),
+            },
+            CadenceValue::Capability { value } => CadenceType::Capability {
+                type_: Box::new(value.borrow_type.clone()),
+            },
+            CadenceValue::Function { value } => value.function_type.clone(),
+        }
+    }
+}
+
+fn field_types(value: &CompositeValue) -> Vec<FieldType> {
+    value
+        .fields
+        .iter()
+        .map(|field| FieldType {
+            id: field.name.to_string(),
+            type_: field.value.infer_type(),
+        })
+        .collect()
+}
+
+// The most specific type covering all of `types`, or `Never` if there are none
+fn unify_all(types: impl Iterator<Item = CadenceType>) -> CadenceType {
+    types.fold(CadenceType::Never, unify)
+}
+
+fn unify(a: CadenceType, b: CadenceType) -> CadenceType {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (CadenceType::Never, other) | (other, CadenceType::Never) => other,
+        (CadenceType::Optional { type_: a }, CadenceType::Optional { type_: b }) => {
+            CadenceType::Optional {
+                type_: Box::new(unify(*a, *b)),
+            }
+        }
+        (a, b) if is_resource_type(&a) && is_resource_type(&b) => CadenceType::AnyResource,
+        _ => CadenceType::AnyStruct,
+    }
+}
+
+fn is_resource_type(ty: &CadenceType) -> bool {
+    matches!(ty, CadenceType::Resource { .. } | CadenceType::AnyResource)
+}
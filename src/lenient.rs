@@ -0,0 +1,82 @@
+// src/lenient.rs
+
+// This file contains the Lenient wrapper, an opt-in for decoding that accepts
+// more Cadence types than the plain FromCadenceValue impls do
+
+use crate::{CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue};
+use serde::{Deserialize, Serialize};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::ops::{Deref, DerefMut};
+
+/// Wraps a value so decoding coerces compatible Cadence types into it.
+///
+/// `Lenient<String>` accepts `String` like `String` itself does, and
+/// additionally an `Address` as its `0x`-prefixed hex string and any integer or
+/// fixed-point value as its decimal string.
+/// Encoding is unchanged and goes through the inner type.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Lenient<T>(pub T);
+
+impl<T> Lenient<T> {
+    /// Returns the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ToCadenceValue> ToCadenceValue for Lenient<T> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        self.0.to_cadence_value()
+    }
+}
+
+impl FromCadenceValue for Lenient<String> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::String { value }
+            | CadenceValue::Address { value }
+            | CadenceValue::Int { value }
+            | CadenceValue::Int8 { value }
+            | CadenceValue::Int16 { value }
+            | CadenceValue::Int32 { value }
+            | CadenceValue::Int64 { value }
+            | CadenceValue::Int128 { value }
+            | CadenceValue::Int256 { value }
+            | CadenceValue::UInt { value }
+            | CadenceValue::UInt8 { value }
+            | CadenceValue::UInt16 { value }
+            | CadenceValue::UInt32 { value }
+            | CadenceValue::UInt64 { value }
+            | CadenceValue::UInt128 { value }
+            | CadenceValue::UInt256 { value }
+            | CadenceValue::Word8 { value }
+            | CadenceValue::Word16 { value }
+            | CadenceValue::Word32 { value }
+            | CadenceValue::Word64 { value }
+            | CadenceValue::Word128 { value }
+            | CadenceValue::Word256 { value }
+            | CadenceValue::Fix64 { value }
+            | CadenceValue::UFix64 { value } => Ok(Lenient(value.clone())),
+            _ => Err(Error::TypeMismatch {
+                expected: "String, Address or a number".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl<T> Deref for Lenient<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Lenient<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
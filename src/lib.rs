@@ -1,14 +1,71 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(unused_variables)]
+extern crate alloc;
+
 pub use serde::{Deserialize, Serialize};
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 use derive_more::From;
 #[cfg(feature = "derive")]
 pub use cadence_json_derive::{FromCadenceValue, ToCadenceValue};
 
+mod address;
+#[cfg(feature = "serde-bridge")]
+mod bridge;
+mod borrowed;
+mod bytes;
+pub mod conversion;
+mod fixed;
+mod flow;
+mod infer;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
 pub mod impls;
+mod lenient;
+mod macros;
+mod pairs;
+pub mod prelude;
+mod pretty;
+mod schema;
+mod stream;
+mod types;
+mod value;
+
+// Used by the derive macros so that generated code also compiles in `no_std`
+// crates, which don't have `String`, `Vec` or `format!` in their prelude
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::format;
+    pub use alloc::string::ToString;
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+}
+
+#[cfg(feature = "serde-bridge")]
+pub use bridge::SerdeBridge;
+pub use borrowed::{CadenceValueRef, CompositeFieldRef, CompositeValueRef, DictionaryEntryRef};
+pub use address::Address;
+pub use bytes::Bytes;
+pub use fixed::{Fix64, UFix64};
+pub use flow::{Account, AccountKey, Block, HashAlgorithm, PublicKey, SignatureAlgorithm};
+pub use lenient::Lenient;
+pub use pairs::Pairs;
+pub use stream::ParseOptions;
+pub use value::Canonical;
+pub use conversion::{
+    ConversionOptions, EncodeOptions, NumberMapping, cadence_value_to_value, cadence_value_to_value_with,
+    value_to_cadence_value, value_to_cadence_value_with,
+};
 
 /// A Cadence value as represented in JSON
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Equality is structural: dictionary entries and composite fields are compared
+/// in order, so `{a: 1, b: 2}` is not equal to `{b: 2, a: 1}`. Wrap values in
+/// [`Canonical`] to compare them regardless of order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum CadenceValue {
     #[serde(rename = "Void")]
@@ -23,8 +80,17 @@ pub enum CadenceValue {
     #[serde(rename = "String")]
     String { value: String },
 
+    #[serde(rename = "Character")]
+    Character { value: String },
+
+    /// With the `strict-address` feature, parsing rejects values that aren't
+    /// `0x` followed by 1 to 16 hex digits
     #[serde(rename = "Address")]
     Address {
+        #[cfg_attr(
+            feature = "strict-address",
+            serde(deserialize_with = "crate::address::deserialize_strict")
+        )]
         value: String, // Hex-encoded string with 0x prefix
     },
 
@@ -136,31 +202,54 @@ pub enum CadenceValue {
     Function { value: FunctionValue },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A Cadence value paired with its static type.
+///
+/// Empty arrays and dictionaries carry no element type on their own, so
+/// argument decoders that need it can read it from `staticType` instead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypedValue {
+    #[serde(flatten)]
+    pub value: CadenceValue,
+    #[serde(rename = "staticType")]
+    pub static_type: CadenceType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DictionaryEntry {
     pub key: CadenceValue,
     pub value: CadenceValue,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// The string type of composite ids and field names.
+///
+/// This is `String`, or `compact_str::CompactString` with the `compact-str`
+/// feature, which stores the short names Cadence uses inline instead of on the
+/// heap. Both convert from `&str` and `String` with `.into()` and compare
+/// equal to `&str`.
+#[cfg(not(feature = "compact-str"))]
+pub type Name = String;
+#[cfg(feature = "compact-str")]
+pub type Name = compact_str::CompactString;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompositeField {
-    pub name: String,
+    pub name: Name,
     pub value: CadenceValue,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompositeValue {
-    pub id: String, // Fully qualified type identifier
+    pub id: Name, // Fully qualified type identifier
     pub fields: Vec<CompositeField>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PathValue {
     pub domain: PathDomain,
     pub identifier: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PathDomain {
     Storage,
@@ -168,32 +257,35 @@ pub enum PathDomain {
     Public,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TypeValue {
+    #[serde(rename = "staticType")]
     pub static_type: CadenceType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RangeValue {
     pub start: Box<CadenceValue>,
     pub end: Box<CadenceValue>,
     pub step: Box<CadenceValue>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CapabilityValue {
     pub id: String,
     pub address: String,
+    #[serde(rename = "borrowType")]
     pub borrow_type: CadenceType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FunctionValue {
+    #[serde(rename = "functionType")]
     pub function_type: CadenceType,
 }
 
 /// Represents a Cadence type in JSON format
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "kind")]
 pub enum CadenceType {
     // Simple types
@@ -208,6 +300,7 @@ pub enum CadenceType {
     Block,
     Bool,
     Capability {
+        #[serde(rename = "type")]
         type_: Box<CadenceType>,
     },
     CapabilityPath,
@@ -259,14 +352,17 @@ pub enum CadenceType {
 
     // Complex types
     Optional {
+        #[serde(rename = "type")]
         type_: Box<CadenceType>,
     },
 
     VariableSizedArray {
+        #[serde(rename = "type")]
         type_: Box<CadenceType>,
     },
 
     ConstantSizedArray {
+        #[serde(rename = "type")]
         type_: Box<CadenceType>,
         size: usize,
     },
@@ -280,6 +376,7 @@ pub enum CadenceType {
     Struct {
         #[serde(rename = "type")]
         type_: String,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
@@ -288,6 +385,7 @@ pub enum CadenceType {
     Resource {
         #[serde(rename = "type")]
         type_: String,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
@@ -296,6 +394,7 @@ pub enum CadenceType {
     Event {
         #[serde(rename = "type")]
         type_: String,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
@@ -304,6 +403,7 @@ pub enum CadenceType {
     Contract {
         #[serde(rename = "type")]
         type_: String,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
@@ -312,6 +412,7 @@ pub enum CadenceType {
     StructInterface {
         #[serde(rename = "type")]
         type_: String,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
@@ -320,6 +421,7 @@ pub enum CadenceType {
     ResourceInterface {
         #[serde(rename = "type")]
         type_: String,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
@@ -328,24 +430,31 @@ pub enum CadenceType {
     ContractInterface {
         #[serde(rename = "type")]
         type_: String,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
     },
 
     Function {
+        #[serde(rename = "typeID")]
         type_id: String,
         parameters: Vec<ParameterType>,
+        /// `"view"` for view functions; Flow leaves the key out otherwise
+        #[serde(default, skip_serializing_if = "Option::is_none")]
         purity: Option<String>,
+        #[serde(rename = "return")]
         return_: Box<CadenceType>,
     },
 
     Reference {
         authorization: Authorization,
+        #[serde(rename = "type")]
         type_: Box<CadenceType>,
     },
 
     Intersection {
+        #[serde(rename = "typeID")]
         type_id: String,
         types: Vec<CadenceType>,
     },
@@ -353,6 +462,7 @@ pub enum CadenceType {
     Enum {
         #[serde(rename = "type")]
         type_: Box<CadenceType>,
+        #[serde(rename = "typeID")]
         type_id: String,
         initializers: Vec<Vec<ParameterType>>,
         fields: Vec<FieldType>,
@@ -363,23 +473,27 @@ pub enum CadenceType {
     },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FieldType {
     pub id: String,
+    #[serde(rename = "type")]
     pub type_: CadenceType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParameterType {
     pub label: String,
     pub id: String,
+    #[serde(rename = "type")]
     pub type_: CadenceType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "kind")]
 pub enum Authorization {
+    /// Flow sends `null` entitlements here, or leaves the key out entirely
     Unauthorized {
+        #[serde(default)]
         entitlements: Option<Vec<Entitlement>>,
     },
     EntitlementMapAuthorization {
@@ -393,11 +507,19 @@ pub enum Authorization {
     },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "kind")]
 pub enum Entitlement {
-    EntitlementMap { type_id: String },
-    // Add other entitlement types as needed
+    /// A plain entitlement, as used in conjunction and disjunction sets
+    #[serde(rename = "Entitlement")]
+    Plain {
+        #[serde(rename = "typeID")]
+        type_id: String,
+    },
+    EntitlementMap {
+        #[serde(rename = "typeID")]
+        type_id: String,
+    },
 }
 
 /// Error types for the Cadence-JSON serialization/deserialization
@@ -411,6 +533,9 @@ pub enum Error {
     #[from]
     Conversion(core::convert::Infallible),
     Custom(String),
+    /// An error raised while converting a nested value, with the path to it
+    /// such as `metadata.tags[2]`
+    AtPath { path: String, source: Box<Error> },
 }
 
 impl fmt::Display for Error {
@@ -423,13 +548,14 @@ impl fmt::Display for Error {
             }
             Error::UnsupportedType(msg) => write!(f, "Unsupported type: {}", msg),
             Error::Custom(msg) => write!(f, "{}", msg),
+            Error::AtPath { path, source } => write!(f, "at {}: {}", path, source),
             Error::Conversion(e) => write!(f, "{}", e),
         }
     }
 }
 
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Error::SerdeJson(err) => Some(err),
             Error::InvalidCadenceValue(_) => None,
@@ -437,12 +563,42 @@ impl std::error::Error for Error {
             Error::UnsupportedType(_) => None,
             Error::Conversion(err) => Some(err),
             Error::Custom(_) => None,
+            Error::AtPath { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+impl Error {
+    /// Records that this error happened inside the composite field `name`
+    pub fn at_field(self, name: &str) -> Error {
+        self.prepend_path(name.to_string())
+    }
+
+    /// Records that this error happened at array or dictionary position `index`
+    pub fn at_index(self, index: usize) -> Error {
+        self.prepend_path(format!("[{}]", index))
+    }
+
+    // Errors bubble up from the innermost value, so segments are added in front
+    fn prepend_path(self, segment: String) -> Error {
+        match self {
+            Error::AtPath { path, source } => {
+                let separator = if path.starts_with('[') { "" } else { "." };
+                Error::AtPath {
+                    path: format!("{}{}{}", segment, separator, path),
+                    source,
+                }
+            }
+            error => Error::AtPath {
+                path: segment,
+                source: Box::new(error),
+            },
         }
     }
 }
 
 /// Result type for Cadence-JSON operations
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// Serializes a Rust type to a Cadence-JSON string
 pub fn to_string<T>(value: &T) -> Result<String>
@@ -484,13 +640,36 @@ where
     Ok(json)
 }
 
+/// Serializes a Rust type as Cadence-JSON into an IO stream
+#[cfg(feature = "std")]
+pub fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: std::io::Write,
+    T: Serialize + ToCadenceValue + ?Sized,
+{
+    let cadence_value = to_cadence_value(value)?;
+    serde_json::to_writer(writer, &cadence_value)?;
+    Ok(())
+}
+
+/// Serializes a Rust type as pretty-printed Cadence-JSON into an IO stream
+#[cfg(feature = "std")]
+pub fn to_writer_pretty<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: std::io::Write,
+    T: Serialize + ToCadenceValue + ?Sized,
+{
+    let cadence_value = to_cadence_value(value)?;
+    serde_json::to_writer_pretty(writer, &cadence_value)?;
+    Ok(())
+}
+
 /// Deserializes a Cadence-JSON string to a Rust type
 pub fn from_str<'a, T>(s: &'a str) -> Result<T>
 where
     T: for<'de> Deserialize<'de> + FromCadenceValue,
 {
-    let cadence_value: CadenceValue = serde_json::from_str(s)?;
-    from_cadence_value(&cadence_value)
+    from_cadence_value(&from_str_value(s)?)
 }
 
 /// Deserializes a Cadence-JSON byte slice to a Rust type
@@ -498,18 +677,67 @@ pub fn from_slice<'a, T>(v: &'a [u8]) -> Result<T>
 where
     T: for<'de> Deserialize<'de> + FromCadenceValue,
 {
-    let cadence_value: CadenceValue = serde_json::from_slice(v)?;
-    from_cadence_value(&cadence_value)
+    from_cadence_value(&from_slice_value(v)?)
+}
+
+/// Parses a Cadence-JSON string into a [`CadenceValueRef`] that borrows its
+/// string payloads from `s` rather than copying them
+pub fn from_str_borrowed(s: &str) -> Result<CadenceValueRef<'_>> {
+    Ok(serde_json::from_str(s)?)
+}
+
+/// Parses a Cadence-JSON byte slice into a [`CadenceValueRef`] that borrows
+/// its string payloads from `v` rather than copying them
+pub fn from_slice_borrowed(v: &[u8]) -> Result<CadenceValueRef<'_>> {
+    Ok(serde_json::from_slice(v)?)
 }
 
 /// Deserializes a Cadence-JSON reader to a Rust type
+#[cfg(feature = "std")]
 pub fn from_reader<R, T>(rdr: R) -> Result<T>
 where
     R: std::io::Read,
     T: for<'de> Deserialize<'de> + FromCadenceValue,
 {
-    let cadence_value: CadenceValue = serde_json::from_reader(rdr)?;
-    from_cadence_value(&cadence_value)
+    from_cadence_value(&from_reader_value(rdr)?)
+}
+
+/// Parses a Cadence-JSON string into a [`CadenceValue`], reading the input
+/// straight into it rather than through an intermediate `serde_json::Value`;
+/// [`from_str`] goes on to convert the result into a Rust type
+pub fn from_str_value(s: &str) -> Result<CadenceValue> {
+    from_str_value_with(s, &ParseOptions::default())
+}
+
+/// Parses a Cadence-JSON byte slice into a [`CadenceValue`]
+pub fn from_slice_value(v: &[u8]) -> Result<CadenceValue> {
+    from_slice_value_with(v, &ParseOptions::default())
+}
+
+/// Parses Cadence-JSON from a reader into a [`CadenceValue`]
+#[cfg(feature = "std")]
+pub fn from_reader_value<R: std::io::Read>(rdr: R) -> Result<CadenceValue> {
+    from_reader_value_with(rdr, &ParseOptions::default())
+}
+
+/// Like [`from_str_value`], but applies the checks the options ask for, e.g.
+/// rejecting duplicate dictionary keys
+pub fn from_str_value_with(s: &str, options: &ParseOptions) -> Result<CadenceValue> {
+    stream::parse(serde_json::Deserializer::from_str(s), options)
+}
+
+/// Like [`from_slice_value`], with [`ParseOptions`]
+pub fn from_slice_value_with(v: &[u8], options: &ParseOptions) -> Result<CadenceValue> {
+    stream::parse(serde_json::Deserializer::from_slice(v), options)
+}
+
+/// Like [`from_reader_value`], with [`ParseOptions`]
+#[cfg(feature = "std")]
+pub fn from_reader_value_with<R: std::io::Read>(
+    rdr: R,
+    options: &ParseOptions,
+) -> Result<CadenceValue> {
+    stream::parse(serde_json::Deserializer::from_reader(rdr), options)
 }
 
 pub fn to_cadence_value<T>(value: &T) -> Result<CadenceValue>
@@ -518,6 +746,43 @@ where
 {
     value.to_cadence_value()
 }
+
+/// Like [`to_cadence_value`], but Rust integers encode as the types the
+/// options pin them to, e.g. every `u64` as an arbitrary-precision `UInt`.
+///
+/// The options apply to the current thread until the call returns; see
+/// [`EncodeOptions`] for which values they cover.
+#[cfg(feature = "std")]
+pub fn to_cadence_value_with<T>(value: &T, options: &EncodeOptions) -> Result<CadenceValue>
+where
+    T: ToCadenceValue + ?Sized,
+{
+    options.scope(|| value.to_cadence_value())
+}
+
+/// Encodes script or transaction arguments as one Cadence-JSON string each,
+/// in order, which is the form Flow's access API expects for arguments
+pub fn encode_arguments(args: &[&dyn ToCadenceValue]) -> Result<Vec<String>> {
+    args.iter()
+        .enumerate()
+        .map(|(index, arg)| {
+            let value = arg.to_cadence_value().map_err(|e| e.at_index(index))?;
+            Ok(serde_json::to_string(&value)?)
+        })
+        .collect()
+}
+
+/// Converts a Rust value to a CadenceValue annotated with its static type,
+/// failing if the value doesn't match that type
+pub fn to_cadence_value_typed<T>(value: &T, static_type: CadenceType) -> Result<TypedValue>
+where
+    T: ToCadenceValue + ?Sized,
+{
+    let value = value.to_cadence_value()?;
+    value.validate_against(&static_type)?;
+    Ok(TypedValue { value, static_type })
+}
+
 pub fn from_cadence_value<T>(cadence_value: &CadenceValue) -> Result<T>
 where
     T: FromCadenceValue,
@@ -569,19 +834,23 @@ where
     })
 }
 
-/// Convert a Rust Map to CadenceValue::Dictionary
-pub fn to_cadence_dictionary<K, V, M>(map: &M) -> Result<CadenceValue>
+/// Convert key-value pairs to CadenceValue::Dictionary, keeping their order
+pub fn to_cadence_dictionary<K, V, I>(entries: I) -> Result<CadenceValue>
 where
-    K: Serialize,
-    V: Serialize,
-    M: std::ops::Deref<Target = dyn std::ops::Index<K, Output = V>>,
-    M::Target: IntoIterator<Item = (K, V)>,
+    K: ToCadenceValue,
+    V: ToCadenceValue,
+    I: IntoIterator<Item = (K, V)>,
 {
-    // This is a placeholder; a real implementation would need to
-    // iterate over the map and convert each key-value pair
-    Err(Error::Custom(
-        "to_cadence_dictionary not fully implemented".to_string(),
-    ))
+    let mut dictionary_entries = Vec::new();
+    for (key, value) in entries {
+        dictionary_entries.push(DictionaryEntry {
+            key: to_cadence_value(&key)?,
+            value: to_cadence_value(&value)?,
+        });
+    }
+    Ok(CadenceValue::Dictionary {
+        value: dictionary_entries,
+    })
 }
 
 // Trait for types that can be converted to a CadenceValue
@@ -0,0 +1,120 @@
+// src/macros.rs
+
+// This file contains the cadence_value! macro for writing CadenceValue
+// literals inline, in the spirit of serde_json::json!
+
+/// Builds a [`CadenceValue`](crate::CadenceValue) from a literal-like syntax.
+///
+/// - `[a, b, c]` builds an `Array` and `{ k1 => v1, k2 => v2 }` a `Dictionary`,
+///   both nesting freely
+/// - `null` builds an empty `Optional`
+/// - anything else is an expression converted with `ToCadenceValue`, so the
+///   integer subtype follows the literal's suffix: `42u64` is a `UInt64`,
+///   `-1i8` an `Int8` and a plain `42` an `Int32`; strings and bools map to
+///   `String` and `Bool`
+///
+/// For example `cadence_value!({ "apple" => [1u8, 2u8], "banana" => null })`.
+/// Like `json!`, this panics if an expression fails to convert, which can't
+/// happen for the primitive types.
+#[macro_export]
+macro_rules! cadence_value {
+    // Array elements, accumulated in `[...]`
+    (@array [$($elems:expr,)*]) => {
+        $crate::__private::vec![$($elems,)*]
+    };
+    (@array [$($elems:expr,)*] , $($rest:tt)*) => {
+        $crate::cadence_value!(@array [$($elems,)*] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] null $($rest:tt)*) => {
+        $crate::cadence_value!(@array [$($elems,)* $crate::cadence_value!(null),] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] [$($inner:tt)*] $($rest:tt)*) => {
+        $crate::cadence_value!(@array [$($elems,)* $crate::cadence_value!([$($inner)*]),] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] {$($inner:tt)*} $($rest:tt)*) => {
+        $crate::cadence_value!(@array [$($elems,)* $crate::cadence_value!({$($inner)*}),] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] $next:expr , $($rest:tt)*) => {
+        $crate::cadence_value!(@array [$($elems,)* $crate::cadence_value!($next),] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] $last:expr) => {
+        $crate::cadence_value!(@array [$($elems,)* $crate::cadence_value!($last),])
+    };
+
+    // Dictionary entries, accumulated in `[...]`
+    (@dict [$($entries:expr,)*]) => {
+        $crate::__private::vec![$($entries,)*]
+    };
+    (@dict [$($entries:expr,)*] , $($rest:tt)*) => {
+        $crate::cadence_value!(@dict [$($entries,)*] $($rest)*)
+    };
+    (@dict [$($entries:expr,)*] $key:expr => null $($rest:tt)*) => {
+        $crate::cadence_value!(
+            @dict [$($entries,)* $crate::cadence_value!(@entry $key, $crate::cadence_value!(null)),]
+            $($rest)*
+        )
+    };
+    (@dict [$($entries:expr,)*] $key:expr => [$($inner:tt)*] $($rest:tt)*) => {
+        $crate::cadence_value!(
+            @dict [$($entries,)* $crate::cadence_value!(@entry $key, $crate::cadence_value!([$($inner)*])),]
+            $($rest)*
+        )
+    };
+    (@dict [$($entries:expr,)*] $key:expr => {$($inner:tt)*} $($rest:tt)*) => {
+        $crate::cadence_value!(
+            @dict [$($entries,)* $crate::cadence_value!(@entry $key, $crate::cadence_value!({$($inner)*})),]
+            $($rest)*
+        )
+    };
+    (@dict [$($entries:expr,)*] $key:expr => $value:expr , $($rest:tt)*) => {
+        $crate::cadence_value!(
+            @dict [$($entries,)* $crate::cadence_value!(@entry $key, $crate::cadence_value!($value)),]
+            $($rest)*
+        )
+    };
+    (@dict [$($entries:expr,)*] $key:expr => $value:expr) => {
+        $crate::cadence_value!(
+            @dict [$($entries,)* $crate::cadence_value!(@entry $key, $crate::cadence_value!($value)),]
+        )
+    };
+    (@entry $key:expr, $value:expr) => {
+        $crate::DictionaryEntry {
+            key: $crate::cadence_value!($key),
+            value: $value,
+        }
+    };
+
+    (null) => {
+        $crate::CadenceValue::Optional { value: None }
+    };
+    ([$($elems:tt)*]) => {
+        $crate::CadenceValue::Array {
+            value: $crate::cadence_value!(@array [] $($elems)*),
+        }
+    };
+    ({$($entries:tt)*}) => {
+        $crate::CadenceValue::Dictionary {
+            value: $crate::cadence_value!(@dict [] $($entries)*),
+        }
+    };
+    ($other:expr) => {
+        $crate::to_cadence_value(&$other).unwrap()
+    };
+}
+
+/// Fails to compile unless every listed type implements both
+/// [`ToCadenceValue`](crate::ToCadenceValue) and
+/// [`FromCadenceValue`](crate::FromCadenceValue).
+///
+/// Libraries exposing Flow-facing types can use this to keep them encodable
+/// and decodable, e.g. `assert_cadence_roundtrip!(Listing, Vec<Listing>);`.
+/// It expands to a `const` item and generates no code.
+#[macro_export]
+macro_rules! assert_cadence_roundtrip {
+    ($($ty:ty),+ $(,)?) => {
+        const _: fn() = || {
+            fn assert_roundtrip<T: $crate::ToCadenceValue + $crate::FromCadenceValue>() {}
+            $(assert_roundtrip::<$ty>();)+
+        };
+    };
+}
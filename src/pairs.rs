@@ -0,0 +1,81 @@
+// src/pairs.rs
+
+// This file contains Pairs, a wrapper encoding an ordered list of key-value
+// pairs as a Cadence dictionary without going through a map type
+
+use crate::{CadenceValue, DictionaryEntry, Error, FromCadenceValue, Result, ToCadenceValue};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+use serde::{Deserialize, Serialize};
+
+/// Wraps a list of key-value pairs so it encodes as a `Dictionary` rather than
+/// an `Array` of tuples.
+///
+/// Entries keep their order in both directions and duplicate keys are passed
+/// through as they are, unlike with `HashMap` or `BTreeMap`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pairs<K, V>(pub Vec<(K, V)>);
+
+impl<K, V> Pairs<K, V> {
+    /// Returns the wrapped pairs
+    pub fn into_inner(self) -> Vec<(K, V)> {
+        self.0
+    }
+}
+
+impl<K, V> From<Vec<(K, V)>> for Pairs<K, V> {
+    fn from(pairs: Vec<(K, V)>) -> Self {
+        Pairs(pairs)
+    }
+}
+
+impl<K: ToCadenceValue, V: ToCadenceValue> ToCadenceValue for Pairs<K, V> {
+    fn to_cadence_value(&self) -> Result<CadenceValue> {
+        let mut entries = Vec::with_capacity(self.0.len());
+        for (index, (key, value)) in self.0.iter().enumerate() {
+            entries.push(DictionaryEntry {
+                key: key.to_cadence_value().map_err(|e| e.at_index(index))?,
+                value: value.to_cadence_value().map_err(|e| e.at_index(index))?,
+            });
+        }
+        Ok(CadenceValue::Dictionary { value: entries })
+    }
+}
+
+impl<K: FromCadenceValue, V: FromCadenceValue> FromCadenceValue for Pairs<K, V> {
+    fn from_cadence_value(value: &CadenceValue) -> Result<Self> {
+        match value {
+            CadenceValue::Dictionary { value } => {
+                let mut pairs = Vec::with_capacity(value.len());
+                for (index, entry) in value.iter().enumerate() {
+                    let key = K::from_cadence_value(&entry.key).map_err(|e| e.at_index(index))?;
+                    let value =
+                        V::from_cadence_value(&entry.value).map_err(|e| e.at_index(index))?;
+                    pairs.push((key, value));
+                }
+                Ok(Pairs(pairs))
+            }
+            _ => Err(Error::TypeMismatch {
+                expected: "Dictionary".to_string(),
+                got: format!("{:?}", value),
+            }),
+        }
+    }
+}
+
+impl<K, V> Deref for Pairs<K, V> {
+    type Target = Vec<(K, V)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> DerefMut for Pairs<K, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
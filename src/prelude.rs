@@ -0,0 +1,6 @@
+// src/prelude.rs
+
+// Re-exports of the names most users need, for `use serde_cadence::prelude::*;`.
+// With the `derive` feature, the trait names also bring the derive macros along.
+
+pub use crate::{CadenceType, CadenceValue, Error, FromCadenceValue, Result, ToCadenceValue};
@@ -0,0 +1,119 @@
+// src/pretty.rs
+
+// This file contains a human-readable rendering of CadenceValue that looks
+// like Cadence source, for logging and inspection rather than parsing
+
+use crate::{CadenceValue, CompositeValue, PathDomain};
+use alloc::string::{String, ToString};
+use core::fmt;
+
+impl CadenceValue {
+    /// Renders this value the way it would be written in Cadence, e.g.
+    /// `Foo(name: "x", age: 30)`, `[1, 2, 3]` or `{"a": 1}`.
+    ///
+    /// Type tags are dropped, so the output is meant for logs and debugging;
+    /// it can't be parsed back into a CadenceValue.
+    pub fn to_pretty_cadence(&self) -> String {
+        Pretty(self).to_string()
+    }
+}
+
+struct Pretty<'a>(&'a CadenceValue);
+
+impl fmt::Display for Pretty<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            CadenceValue::Void {} => f.write_str("()"),
+            CadenceValue::Optional { value } => match value {
+                Some(inner) => Pretty(inner).fmt(f),
+                None => f.write_str("nil"),
+            },
+            CadenceValue::Bool { value } => write!(f, "{}", value),
+            CadenceValue::String { value } | CadenceValue::Character { value } => {
+                write!(f, "{:?}", value)
+            }
+            CadenceValue::Address { value }
+            | CadenceValue::Int { value }
+            | CadenceValue::Int8 { value }
+            | CadenceValue::Int16 { value }
+            | CadenceValue::Int32 { value }
+            | CadenceValue::Int64 { value }
+            | CadenceValue::Int128 { value }
+            | CadenceValue::Int256 { value }
+            | CadenceValue::UInt { value }
+            | CadenceValue::UInt8 { value }
+            | CadenceValue::UInt16 { value }
+            | CadenceValue::UInt32 { value }
+            | CadenceValue::UInt64 { value }
+            | CadenceValue::UInt128 { value }
+            | CadenceValue::UInt256 { value }
+            | CadenceValue::Word8 { value }
+            | CadenceValue::Word16 { value }
+            | CadenceValue::Word32 { value }
+            | CadenceValue::Word64 { value }
+            | CadenceValue::Word128 { value }
+            | CadenceValue::Word256 { value }
+            | CadenceValue::Fix64 { value }
+            | CadenceValue::UFix64 { value } => f.write_str(value),
+            CadenceValue::Array { value } => {
+                f.write_str("[")?;
+                for (index, item) in value.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    Pretty(item).fmt(f)?;
+                }
+                f.write_str("]")
+            }
+            CadenceValue::Dictionary { value } => {
+                f.write_str("{")?;
+                for (index, entry) in value.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}: {}", Pretty(&entry.key), Pretty(&entry.value))?;
+                }
+                f.write_str("}")
+            }
+            CadenceValue::Struct { value }
+            | CadenceValue::Resource { value }
+            | CadenceValue::Event { value }
+            | CadenceValue::Contract { value }
+            | CadenceValue::Enum { value } => fmt_composite(value, f),
+            CadenceValue::Path { value } => {
+                let domain = match value.domain {
+                    PathDomain::Storage => "storage",
+                    PathDomain::Private => "private",
+                    PathDomain::Public => "public",
+                };
+                write!(f, "/{}/{}", domain, value.identifier)
+            }
+            CadenceValue::Type { value } => write!(f, "Type<{}>()", value.static_type),
+            CadenceValue::InclusiveRange { value } => write!(
+                f,
+                "InclusiveRange({}, {}, step: {})",
+                Pretty(&value.start),
+                Pretty(&value.end),
+                Pretty(&value.step)
+            ),
+            CadenceValue::Capability { value } => write!(
+                f,
+                "Capability<{}>(address: {}, id: {})",
+                value.borrow_type, value.address, value.id
+            ),
+            CadenceValue::Function { value } => write!(f, "{}", value.function_type),
+        }
+    }
+}
+
+// Composites render like a constructor call: `Id(field: value, ...)`
+fn fmt_composite(composite: &CompositeValue, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}(", composite.id)?;
+    for (index, field) in composite.fields.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}: {}", field.name, Pretty(&field.value))?;
+    }
+    f.write_str(")")
+}
@@ -0,0 +1,334 @@
+// src/schema.rs
+
+// This file contains validation of a CadenceValue against an expected
+// CadenceType, for checking untrusted values before using them
+
+use crate::{CadenceType, CadenceValue, CompositeValue, Error, FieldType, PathDomain, Result};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+impl CadenceValue {
+    /// Checks that this value matches the given type, recursing into nested
+    /// values and declared composite fields.
+    ///
+    /// On failure returns an `Error::TypeMismatch` naming the `/`-separated
+    /// path to the offending value, in the same syntax as [`CadenceValue::get`].
+    pub fn validate_against(&self, ty: &CadenceType) -> Result<()> {
+        validate(self, ty, "")
+    }
+}
+
+fn validate(value: &CadenceValue, ty: &CadenceType, path: &str) -> Result<()> {
+    let matches = match ty {
+        CadenceType::Never => false,
+        CadenceType::AnyStruct => !matches!(value, CadenceValue::Resource { .. }),
+        CadenceType::AnyResource => matches!(value, CadenceValue::Resource { .. }),
+        CadenceType::Number => is_integer(value) || is_fixed_point(value),
+        CadenceType::SignedNumber => {
+            is_signed_integer(value) || matches!(value, CadenceValue::Fix64 { .. })
+        }
+        CadenceType::Integer => is_integer(value),
+        CadenceType::SignedInteger => is_signed_integer(value),
+        CadenceType::FixedSizeUnsignedInteger => {
+            is_integer(value)
+                && !is_signed_integer(value)
+                && !matches!(value, CadenceValue::UInt { .. })
+        }
+        CadenceType::FixedPoint => is_fixed_point(value),
+        CadenceType::SignedFixedPoint => matches!(value, CadenceValue::Fix64 { .. }),
+        CadenceType::HashableStruct => {
+            is_integer(value)
+                || is_fixed_point(value)
+                || matches!(
+                    value,
+                    CadenceValue::Bool { .. }
+                        | CadenceValue::String { .. }
+                        | CadenceValue::Character { .. }
+                        | CadenceValue::Address { .. }
+                        | CadenceValue::Path { .. }
+                        | CadenceValue::Enum { .. }
+                )
+        }
+        CadenceType::Path => matches!(value, CadenceValue::Path { .. }),
+        CadenceType::StoragePath => is_path_in(value, &[PathDomain::Storage]),
+        CadenceType::PublicPath => is_path_in(value, &[PathDomain::Public]),
+        CadenceType::PrivatePath => is_path_in(value, &[PathDomain::Private]),
+        CadenceType::CapabilityPath => {
+            is_path_in(value, &[PathDomain::Public, PathDomain::Private])
+        }
+
+        // Built-in composite types
+        CadenceType::Account
+        | CadenceType::AccountCapabilityController
+        | CadenceType::AccountKey
+        | CadenceType::AnyResourceAttachment
+        | CadenceType::AnyStructAttachment
+        | CadenceType::Block
+        | CadenceType::DeployedContract
+        | CadenceType::DeploymentResult
+        | CadenceType::HashAlgorithm
+        | CadenceType::PublicKey
+        | CadenceType::SignatureAlgorithm
+        | CadenceType::StorageCapabilityController => composite(value).is_some(),
+
+        CadenceType::Optional { type_ } => match value {
+            CadenceValue::Optional { value: None } => true,
+            CadenceValue::Optional { value: Some(inner) } => {
+                return validate(inner, type_, path);
+            }
+            _ => false,
+        },
+        CadenceType::VariableSizedArray { type_ }
+        | CadenceType::ConstantSizedArray { type_, .. } => match value {
+            CadenceValue::Array { value } => {
+                if let CadenceType::ConstantSizedArray { size, .. } = ty
+                    && value.len() != *size
+                {
+                    return Err(Error::InvalidCadenceValue(format!(
+                        "expected {} elements for {} at `{}`, got {}",
+                        size,
+                        ty.type_id(),
+                        display_path(path),
+                        value.len()
+                    )));
+                }
+                for (index, item) in value.iter().enumerate() {
+                    validate(item, type_, &child_path(path, &index.to_string()))?;
+                }
+                true
+            }
+            _ => false,
+        },
+        CadenceType::Dictionary {
+            key,
+            value: value_type,
+        } => match value {
+            CadenceValue::Dictionary { value } => {
+                for (index, entry) in value.iter().enumerate() {
+                    let entry_path = child_path(path, &index.to_string());
+                    validate(&entry.key, key, &child_path(&entry_path, "key"))?;
+                    validate(&entry.value, value_type, &child_path(&entry_path, "value"))?;
+                }
+                true
+            }
+            _ => false,
+        },
+        CadenceType::InclusiveRange { element } => match value {
+            CadenceValue::InclusiveRange { value } => {
+                validate(&value.start, element, &child_path(path, "start"))?;
+                validate(&value.end, element, &child_path(path, "end"))?;
+                validate(&value.step, element, &child_path(path, "step"))?;
+                true
+            }
+            _ => false,
+        },
+        CadenceType::Capability { .. } => matches!(value, CadenceValue::Capability { .. }),
+        CadenceType::Function { .. } => matches!(value, CadenceValue::Function { .. }),
+        // References are not values in Cadence-JSON, so check the referenced type
+        CadenceType::Reference { type_, .. } => return validate(value, type_, path),
+        CadenceType::Intersection { .. } => matches!(
+            value,
+            CadenceValue::Struct { .. } | CadenceValue::Resource { .. }
+        ),
+
+        CadenceType::Struct {
+            type_id, fields, ..
+        } => match value {
+            CadenceValue::Struct { value } => validate_composite(value, type_id, fields, path)?,
+            _ => false,
+        },
+        CadenceType::Resource {
+            type_id, fields, ..
+        } => match value {
+            CadenceValue::Resource { value } => validate_composite(value, type_id, fields, path)?,
+            _ => false,
+        },
+        CadenceType::Event {
+            type_id, fields, ..
+        } => match value {
+            CadenceValue::Event { value } => validate_composite(value, type_id, fields, path)?,
+            _ => false,
+        },
+        CadenceType::Contract {
+            type_id, fields, ..
+        } => match value {
+            CadenceValue::Contract { value } => validate_composite(value, type_id, fields, path)?,
+            _ => false,
+        },
+        CadenceType::Enum {
+            type_id, fields, ..
+        } => match value {
+            CadenceValue::Enum { value } => validate_composite(value, type_id, fields, path)?,
+            _ => false,
+        },
+        CadenceType::StructInterface { .. } => matches!(value, CadenceValue::Struct { .. }),
+        CadenceType::ResourceInterface { .. } => {
+            matches!(value, CadenceValue::Resource { .. })
+        }
+        CadenceType::ContractInterface { .. } => {
+            matches!(value, CadenceValue::Contract { .. })
+        }
+
+        // Every remaining type is a concrete type whose name is the value's tag
+        _ => type_name(value) == ty.type_id(),
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        Err(mismatch(ty.type_id(), value, path))
+    }
+}
+
+// Checks the composite's type id and each declared field; returns false if the
+// id doesn't match so the caller reports the mismatch at this level
+fn validate_composite(
+    value: &CompositeValue,
+    type_id: &str,
+    fields: &[FieldType],
+    path: &str,
+) -> Result<bool> {
+    if !type_id.is_empty() && value.id != type_id {
+        return Ok(false);
+    }
+    for field_type in fields {
+        let field_path = child_path(path, &field_type.id);
+        match value
+            .fields
+            .iter()
+            .find(|field| field.name == field_type.id)
+        {
+            Some(field) => validate(&field.value, &field_type.type_, &field_path)?,
+            None => {
+                return Err(Error::TypeMismatch {
+                    expected: format!("{} at `{}`", field_type.type_.type_id(), field_path),
+                    got: "missing field".to_string(),
+                });
+            }
+        }
+    }
+    Ok(true)
+}
+
+fn mismatch(expected: String, value: &CadenceValue, path: &str) -> Error {
+    Error::TypeMismatch {
+        expected: format!("{} at `{}`", expected, display_path(path)),
+        got: format!("{:?}", value),
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() { "/" } else { path }
+}
+
+fn child_path(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_string()
+    } else {
+        format!("{}/{}", path, segment)
+    }
+}
+
+fn composite(value: &CadenceValue) -> Option<&CompositeValue> {
+    match value {
+        CadenceValue::Struct { value }
+        | CadenceValue::Resource { value }
+        | CadenceValue::Event { value }
+        | CadenceValue::Contract { value }
+        | CadenceValue::Enum { value } => Some(value),
+        _ => None,
+    }
+}
+
+fn is_path_in(value: &CadenceValue, domains: &[PathDomain]) -> bool {
+    match value {
+        CadenceValue::Path { value } => domains.contains(&value.domain),
+        _ => false,
+    }
+}
+
+fn is_signed_integer(value: &CadenceValue) -> bool {
+    matches!(
+        value,
+        CadenceValue::Int { .. }
+            | CadenceValue::Int8 { .. }
+            | CadenceValue::Int16 { .. }
+            | CadenceValue::Int32 { .. }
+            | CadenceValue::Int64 { .. }
+            | CadenceValue::Int128 { .. }
+            | CadenceValue::Int256 { .. }
+    )
+}
+
+fn is_integer(value: &CadenceValue) -> bool {
+    is_signed_integer(value)
+        || matches!(
+            value,
+            CadenceValue::UInt { .. }
+                | CadenceValue::UInt8 { .. }
+                | CadenceValue::UInt16 { .. }
+                | CadenceValue::UInt32 { .. }
+                | CadenceValue::UInt64 { .. }
+                | CadenceValue::UInt128 { .. }
+                | CadenceValue::UInt256 { .. }
+                | CadenceValue::Word8 { .. }
+                | CadenceValue::Word16 { .. }
+                | CadenceValue::Word32 { .. }
+                | CadenceValue::Word64 { .. }
+                | CadenceValue::Word128 { .. }
+                | CadenceValue::Word256 { .. }
+        )
+}
+
+fn is_fixed_point(value: &CadenceValue) -> bool {
+    matches!(
+        value,
+        CadenceValue::Fix64 { .. } | CadenceValue::UFix64 { .. }
+    )
+}
+
+// The Cadence-JSON `type` tag of a value
+fn type_name(value: &CadenceValue) -> &'static str {
+    match value {
+        CadenceValue::Void {} => "Void",
+        CadenceValue::Optional { .. } => "Optional",
+        CadenceValue::Bool { .. } => "Bool",
+        CadenceValue::String { .. } => "String",
+        CadenceValue::Character { .. } => "Character",
+        CadenceValue::Address { .. } => "Address",
+        CadenceValue::Int { .. } => "Int",
+        CadenceValue::Int8 { .. } => "Int8",
+        CadenceValue::Int16 { .. } => "Int16",
+        CadenceValue::Int32 { .. } => "Int32",
+        CadenceValue::Int64 { .. } => "Int64",
+        CadenceValue::Int128 { .. } => "Int128",
+        CadenceValue::Int256 { .. } => "Int256",
+        CadenceValue::UInt { .. } => "UInt",
+        CadenceValue::UInt8 { .. } => "UInt8",
+        CadenceValue::UInt16 { .. } => "UInt16",
+        CadenceValue::UInt32 { .. } => "UInt32",
+        CadenceValue::UInt64 { .. } => "UInt64",
+        CadenceValue::UInt128 { .. } => "UInt128",
+        CadenceValue::UInt256 { .. } => "UInt256",
+        CadenceValue::Word8 { .. } => "Word8",
+        CadenceValue::Word16 { .. } => "Word16",
+        CadenceValue::Word32 { .. } => "Word32",
+        CadenceValue::Word64 { .. } => "Word64",
+        CadenceValue::Word128 { .. } => "Word128",
+        CadenceValue::Word256 { .. } => "Word256",
+        CadenceValue::Fix64 { .. } => "Fix64",
+        CadenceValue::UFix64 { .. } => "UFix64",
+        CadenceValue::Array { .. } => "Array",
+        CadenceValue::Dictionary { .. } => "Dictionary",
+        CadenceValue::Struct { .. } => "Struct",
+        CadenceValue::Resource { .. } => "Resource",
+        CadenceValue::Event { .. } => "Event",
+        CadenceValue::Contract { .. } => "Contract",
+        CadenceValue::Enum { .. } => "Enum",
+        CadenceValue::Path { .. } => "Path",
+        CadenceValue::Type { .. } => "Type",
+        CadenceValue::InclusiveRange { .. } => "InclusiveRange",
+        CadenceValue::Capability { .. } => "Capability",
+        CadenceValue::Function { .. } => "Function",
+    }
+}
@@ -0,0 +1,569 @@
+// src/stream.rs
+
+// This file contains the streaming parser for Cadence-JSON, used by `from_str`
+// and friends as well as the borrowing `from_str_borrowed`. The derived
+// `Deserialize` for the internally tagged `CadenceValue` buffers every object
+// before it can look at `type`; here the `value` is parsed straight into the
+// right variant whenever `type` comes first, which is how Flow and this crate
+// write it. Strings are borrowed from the input where possible, and the owned
+// entry points copy them out with `CadenceValueRef::into_owned`.
+
+use crate::{
+    CadenceValue, CadenceValueRef, CapabilityValue, CompositeFieldRef, CompositeValueRef,
+    DictionaryEntryRef, FunctionValue, PathValue, RangeValue, Result, TypeValue,
+};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::Deserialize;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+const TYPES: &[&str] = &[
+    "Void",
+    "Optional",
+    "Bool",
+    "String",
+    "Character",
+    "Address",
+    "Int",
+    "Int8",
+    "Int16",
+    "Int32",
+    "Int64",
+    "Int128",
+    "Int256",
+    "UInt",
+    "UInt8",
+    "UInt16",
+    "UInt32",
+    "UInt64",
+    "UInt128",
+    "UInt256",
+    "Word8",
+    "Word16",
+    "Word32",
+    "Word64",
+    "Word128",
+    "Word256",
+    "Fix64",
+    "UFix64",
+    "Array",
+    "Dictionary",
+    "Struct",
+    "Resource",
+    "Event",
+    "Contract",
+    "Enum",
+    "Path",
+    "Type",
+    "InclusiveRange",
+    "Capability",
+    "Function",
+];
+
+/// Options for [`from_str_value_with`](crate::from_str_value_with) and the
+/// other `_with` parsing functions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Reject dictionaries with two keys that are equal in canonical form, as
+    /// Cadence does, see [`CadenceValue::check_dictionary_keys`]
+    pub unique_keys: bool,
+}
+
+/// Parses a whole Cadence-JSON document, rejecting trailing characters
+pub(crate) fn parse<'de, R>(
+    mut deserializer: serde_json::Deserializer<R>,
+    options: &ParseOptions,
+) -> Result<CadenceValue>
+where
+    R: serde_json::de::Read<'de>,
+{
+    let value = CadenceValueRef::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    let value = value.into_owned();
+    if options.unique_keys {
+        value.check_dictionary_keys()?;
+    }
+    Ok(value)
+}
+
+impl<'de> Deserialize<'de> for CadenceValueRef<'de> {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ValueVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum ValueKey {
+    Type,
+    Value,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum PairKey {
+    Key,
+    Name,
+    Value,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum CompositeKey {
+    Id,
+    Fields,
+    #[serde(other)]
+    Other,
+}
+
+// Parses a `{"type": ..., "value": ...}` object
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = CadenceValueRef<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Cadence-JSON value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut type_: Option<Cow<'de, str>> = None;
+        let mut value: Option<CadenceValueRef<'de>> = None;
+        // A `value` before its `type` is kept as plain JSON, and so ends up owned
+        let mut pending: Option<serde_json::Value> = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ValueKey::Type => {
+                    if type_.is_some() {
+                        return Err(de::Error::duplicate_field("type"));
+                    }
+                    type_ = Some(map.next_value_seed(CowStr)?);
+                }
+                ValueKey::Value => {
+                    if value.is_some() || pending.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    match &type_ {
+                        Some(type_) => value = Some(map.next_value_seed(TypedSeed(type_))?),
+                        None => pending = Some(map.next_value()?),
+                    }
+                }
+                ValueKey::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let type_ = type_.ok_or_else(|| de::Error::missing_field("type"))?;
+        if let Some(value) = value {
+            return Ok(value);
+        }
+        match pending {
+            Some(pending) => TypedSeed(&type_)
+                .deserialize(pending)
+                .map_err(de::Error::custom),
+            // Only types whose value may be left out
+            None => match &*type_ {
+                "Void" => Ok(CadenceValueRef::Void {}),
+                "Optional" => Ok(CadenceValueRef::Optional { value: None }),
+                _ if TYPES.contains(&&*type_) => Err(de::Error::missing_field("value")),
+                _ => Err(de::Error::unknown_variant(&type_, TYPES)),
+            },
+        }
+    }
+}
+
+// Parses the `value` of a Cadence-JSON value of the given type
+struct TypedSeed<'a>(&'a str);
+
+impl<'de> DeserializeSeed<'de> for TypedSeed<'_> {
+    type Value = CadenceValueRef<'de>;
+
+    fn deserialize<D>(self, d: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match self.0 {
+            "Void" => {
+                IgnoredAny::deserialize(d)?;
+                CadenceValueRef::Void {}
+            }
+            "Optional" => CadenceValueRef::Optional {
+                value: d.deserialize_option(OptionalVisitor)?,
+            },
+            "Bool" => CadenceValueRef::Bool {
+                value: bool::deserialize(d)?,
+            },
+            "Address" => {
+                let value = CowStr.deserialize(d)?;
+                #[cfg(feature = "strict-address")]
+                crate::address::check_strict(&value)?;
+                CadenceValueRef::Address { value }
+            }
+            "String" => CadenceValueRef::String {
+                value: CowStr.deserialize(d)?,
+            },
+            "Character" => CadenceValueRef::Character {
+                value: CowStr.deserialize(d)?,
+            },
+            "Int" => CadenceValueRef::Int {
+                value: CowStr.deserialize(d)?,
+            },
+            "Int8" => CadenceValueRef::Int8 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Int16" => CadenceValueRef::Int16 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Int32" => CadenceValueRef::Int32 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Int64" => CadenceValueRef::Int64 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Int128" => CadenceValueRef::Int128 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Int256" => CadenceValueRef::Int256 {
+                value: CowStr.deserialize(d)?,
+            },
+            "UInt" => CadenceValueRef::UInt {
+                value: CowStr.deserialize(d)?,
+            },
+            "UInt8" => CadenceValueRef::UInt8 {
+                value: CowStr.deserialize(d)?,
+            },
+            "UInt16" => CadenceValueRef::UInt16 {
+                value: CowStr.deserialize(d)?,
+            },
+            "UInt32" => CadenceValueRef::UInt32 {
+                value: CowStr.deserialize(d)?,
+            },
+            "UInt64" => CadenceValueRef::UInt64 {
+                value: CowStr.deserialize(d)?,
+            },
+            "UInt128" => CadenceValueRef::UInt128 {
+                value: CowStr.deserialize(d)?,
+            },
+            "UInt256" => CadenceValueRef::UInt256 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Word8" => CadenceValueRef::Word8 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Word16" => CadenceValueRef::Word16 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Word32" => CadenceValueRef::Word32 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Word64" => CadenceValueRef::Word64 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Word128" => CadenceValueRef::Word128 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Word256" => CadenceValueRef::Word256 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Fix64" => CadenceValueRef::Fix64 {
+                value: CowStr.deserialize(d)?,
+            },
+            "UFix64" => CadenceValueRef::UFix64 {
+                value: CowStr.deserialize(d)?,
+            },
+            "Array" => CadenceValueRef::Array {
+                value: d.deserialize_seq(ArrayVisitor)?,
+            },
+            "Dictionary" => CadenceValueRef::Dictionary {
+                value: d.deserialize_seq(DictionaryVisitor)?,
+            },
+            "Struct" => CadenceValueRef::Struct {
+                value: d.deserialize_map(CompositeVisitor)?,
+            },
+            "Resource" => CadenceValueRef::Resource {
+                value: d.deserialize_map(CompositeVisitor)?,
+            },
+            "Event" => CadenceValueRef::Event {
+                value: d.deserialize_map(CompositeVisitor)?,
+            },
+            "Contract" => CadenceValueRef::Contract {
+                value: d.deserialize_map(CompositeVisitor)?,
+            },
+            "Enum" => CadenceValueRef::Enum {
+                value: d.deserialize_map(CompositeVisitor)?,
+            },
+            // These rarely nest deeply, so the derived impls are fine
+            "Path" => CadenceValueRef::Path {
+                value: PathValue::deserialize(d)?,
+            },
+            "Type" => CadenceValueRef::Type {
+                value: TypeValue::deserialize(d)?,
+            },
+            "InclusiveRange" => CadenceValueRef::InclusiveRange {
+                value: RangeValue::deserialize(d)?,
+            },
+            "Capability" => CadenceValueRef::Capability {
+                value: CapabilityValue::deserialize(d)?,
+            },
+            "Function" => CadenceValueRef::Function {
+                value: FunctionValue::deserialize(d)?,
+            },
+            other => return Err(de::Error::unknown_variant(other, TYPES)),
+        })
+    }
+}
+
+// Borrows the string from the input when it has no escapes to decode
+struct CowStr;
+
+impl<'de> DeserializeSeed<'de> for CowStr {
+    type Value = Cow<'de, str>;
+
+    fn deserialize<D>(self, d: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for CowStr {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Borrowed(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Owned(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> core::result::Result<Self::Value, E> {
+        Ok(Cow::Owned(v))
+    }
+}
+
+struct OptionalVisitor;
+
+impl<'de> Visitor<'de> for OptionalVisitor {
+    type Value = Option<Box<CadenceValueRef<'de>>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Cadence-JSON value or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> core::result::Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> core::result::Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, d: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        CadenceValueRef::deserialize(d).map(|value| Some(Box::new(value)))
+    }
+}
+
+struct ArrayVisitor;
+
+impl<'de> Visitor<'de> for ArrayVisitor {
+    type Value = Vec<CadenceValueRef<'de>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of Cadence-JSON values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+}
+
+struct DictionaryVisitor;
+
+impl<'de> Visitor<'de> for DictionaryVisitor {
+    type Value = Vec<DictionaryEntryRef<'de>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of dictionary entries")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(entry) = seq.next_element()? {
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+impl<'de> Deserialize<'de> for DictionaryEntryRef<'de> {
+    fn deserialize<D>(d: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_map(EntryVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompositeFieldRef<'de> {
+    fn deserialize<D>(d: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_map(FieldVisitor)
+    }
+}
+
+struct EntryVisitor;
+
+impl<'de> Visitor<'de> for EntryVisitor {
+    type Value = DictionaryEntryRef<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a dictionary entry")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut key = None;
+        let mut value = None;
+        while let Some(name) = map.next_key()? {
+            match name {
+                PairKey::Key => {
+                    if key.is_some() {
+                        return Err(de::Error::duplicate_field("key"));
+                    }
+                    key = Some(map.next_value()?);
+                }
+                PairKey::Value => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    value = Some(map.next_value()?);
+                }
+                PairKey::Name | PairKey::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(DictionaryEntryRef {
+            key: key.ok_or_else(|| de::Error::missing_field("key"))?,
+            value: value.ok_or_else(|| de::Error::missing_field("value"))?,
+        })
+    }
+}
+
+struct FieldVisitor;
+
+impl<'de> Visitor<'de> for FieldVisitor {
+    type Value = CompositeFieldRef<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a composite field")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut name = None;
+        let mut value = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                PairKey::Name => {
+                    if name.is_some() {
+                        return Err(de::Error::duplicate_field("name"));
+                    }
+                    name = Some(map.next_value_seed(CowStr)?);
+                }
+                PairKey::Value => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    value = Some(map.next_value()?);
+                }
+                PairKey::Key | PairKey::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(CompositeFieldRef {
+            name: name.ok_or_else(|| de::Error::missing_field("name"))?,
+            value: value.ok_or_else(|| de::Error::missing_field("value"))?,
+        })
+    }
+}
+
+struct CompositeVisitor;
+
+impl<'de> Visitor<'de> for CompositeVisitor {
+    type Value = CompositeValueRef<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a composite value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut id = None;
+        let mut fields: Option<Vec<CompositeFieldRef<'de>>> = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                CompositeKey::Id => {
+                    if id.is_some() {
+                        return Err(de::Error::duplicate_field("id"));
+                    }
+                    id = Some(map.next_value_seed(CowStr)?);
+                }
+                CompositeKey::Fields => {
+                    if fields.is_some() {
+                        return Err(de::Error::duplicate_field("fields"));
+                    }
+                    fields = Some(map.next_value()?);
+                }
+                CompositeKey::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(CompositeValueRef {
+            id: id.ok_or_else(|| de::Error::missing_field("id"))?,
+            fields: fields.ok_or_else(|| de::Error::missing_field("fields"))?,
+        })
+    }
+}
@@ -0,0 +1,502 @@
+// src/types.rs
+
+// This file contains helpers for working with CadenceType in the compact
+// type-id notation used by Cadence and Flow tooling
+
+use crate::{Authorization, CadenceType, Entitlement, Error, FieldType, ParameterType, Result};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+impl CadenceType {
+    /// Builds an unauthorized reference `&T` to the given type
+    pub fn reference(ty: CadenceType) -> CadenceType {
+        CadenceType::Reference {
+            authorization: Authorization::Unauthorized { entitlements: None },
+            type_: Box::new(ty),
+        }
+    }
+
+    /// Builds an authorized reference `auth(E1, E2) &T` whose authorization is
+    /// the conjunction of the given entitlements
+    pub fn auth_reference<I, S>(entitlements: I, ty: CadenceType) -> CadenceType
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let entitlements = entitlements
+            .into_iter()
+            .map(|type_id| Entitlement::Plain {
+                type_id: type_id.into(),
+            })
+            .collect();
+        CadenceType::Reference {
+            authorization: Authorization::EntitlementConjunctionSet { entitlements },
+            type_: Box::new(ty),
+        }
+    }
+
+    /// Builds a struct type with the given type id, initializers and fields,
+    /// leaving the `type` field empty as Flow does
+    pub fn struct_type(
+        type_id: impl Into<String>,
+        initializers: Vec<Vec<ParameterType>>,
+        fields: Vec<FieldType>,
+    ) -> CadenceType {
+        CadenceType::Struct {
+            type_: String::new(),
+            type_id: type_id.into(),
+            initializers,
+            fields,
+        }
+    }
+
+    /// Returns the canonical Cadence type-id string for this type,
+    /// e.g. `[String]`, `{Address: UInt64}` or `&A.0x1.Foo.Bar`
+    pub fn type_id(&self) -> String {
+        match self {
+            CadenceType::Account => "Account".to_string(),
+            CadenceType::AccountCapabilityController => "AccountCapabilityController".to_string(),
+            CadenceType::AccountKey => "AccountKey".to_string(),
+            CadenceType::Address => "Address".to_string(),
+            CadenceType::AnyResource => "AnyResource".to_string(),
+            CadenceType::AnyResourceAttachment => "AnyResourceAttachment".to_string(),
+            CadenceType::AnyStruct => "AnyStruct".to_string(),
+            CadenceType::AnyStructAttachment => "AnyStructAttachment".to_string(),
+            CadenceType::Block => "Block".to_string(),
+            CadenceType::Bool => "Bool".to_string(),
+            CadenceType::CapabilityPath => "CapabilityPath".to_string(),
+            CadenceType::Character => "Character".to_string(),
+            CadenceType::DeployedContract => "DeployedContract".to_string(),
+            CadenceType::DeploymentResult => "DeploymentResult".to_string(),
+            CadenceType::Fix64 => "Fix64".to_string(),
+            CadenceType::FixedPoint => "FixedPoint".to_string(),
+            CadenceType::FixedSizeUnsignedInteger => "FixedSizeUnsignedInteger".to_string(),
+            CadenceType::HashAlgorithm => "HashAlgorithm".to_string(),
+            CadenceType::HashableStruct => "HashableStruct".to_string(),
+            CadenceType::Int => "Int".to_string(),
+            CadenceType::Int8 => "Int8".to_string(),
+            CadenceType::Int16 => "Int16".to_string(),
+            CadenceType::Int32 => "Int32".to_string(),
+            CadenceType::Int64 => "Int64".to_string(),
+            CadenceType::Int128 => "Int128".to_string(),
+            CadenceType::Int256 => "Int256".to_string(),
+            CadenceType::Integer => "Integer".to_string(),
+            CadenceType::Never => "Never".to_string(),
+            CadenceType::Number => "Number".to_string(),
+            CadenceType::Path => "Path".to_string(),
+            CadenceType::PrivatePath => "PrivatePath".to_string(),
+            CadenceType::PublicKey => "PublicKey".to_string(),
+            CadenceType::PublicPath => "PublicPath".to_string(),
+            CadenceType::SignatureAlgorithm => "SignatureAlgorithm".to_string(),
+            CadenceType::SignedFixedPoint => "SignedFixedPoint".to_string(),
+            CadenceType::SignedInteger => "SignedInteger".to_string(),
+            CadenceType::SignedNumber => "SignedNumber".to_string(),
+            CadenceType::StorageCapabilityController => "StorageCapabilityController".to_string(),
+            CadenceType::StoragePath => "StoragePath".to_string(),
+            CadenceType::String => "String".to_string(),
+            CadenceType::Type => "Type".to_string(),
+            CadenceType::UFix64 => "UFix64".to_string(),
+            CadenceType::UInt => "UInt".to_string(),
+            CadenceType::UInt8 => "UInt8".to_string(),
+            CadenceType::UInt16 => "UInt16".to_string(),
+            CadenceType::UInt32 => "UInt32".to_string(),
+            CadenceType::UInt64 => "UInt64".to_string(),
+            CadenceType::UInt128 => "UInt128".to_string(),
+            CadenceType::UInt256 => "UInt256".to_string(),
+            CadenceType::Void => "Void".to_string(),
+            CadenceType::Word8 => "Word8".to_string(),
+            CadenceType::Word16 => "Word16".to_string(),
+            CadenceType::Word32 => "Word32".to_string(),
+            CadenceType::Word64 => "Word64".to_string(),
+            CadenceType::Word128 => "Word128".to_string(),
+            CadenceType::Word256 => "Word256".to_string(),
+
+            CadenceType::Capability { type_ } => format!("Capability<{}>", type_.type_id()),
+            CadenceType::Optional { type_ } => format!("{}?", type_.type_id()),
+            CadenceType::VariableSizedArray { type_ } => format!("[{}]", type_.type_id()),
+            CadenceType::ConstantSizedArray { type_, size } => {
+                format!("[{}; {}]", type_.type_id(), size)
+            }
+            CadenceType::Dictionary { key, value } => {
+                format!("{{{}: {}}}", key.type_id(), value.type_id())
+            }
+            CadenceType::InclusiveRange { element } => {
+                format!("InclusiveRange<{}>", element.type_id())
+            }
+            CadenceType::Reference {
+                authorization,
+                type_,
+            } => match authorization_type_id(authorization) {
+                Some(auth) => format!("{} &{}", auth, type_.type_id()),
+                None => format!("&{}", type_.type_id()),
+            },
+            CadenceType::Intersection { type_id, types } => {
+                if !type_id.is_empty() {
+                    return type_id.clone();
+                }
+                let types: Vec<String> = types.iter().map(CadenceType::type_id).collect();
+                format!("{{{}}}", types.join(", "))
+            }
+
+            CadenceType::Struct { type_id, .. }
+            | CadenceType::Resource { type_id, .. }
+            | CadenceType::Event { type_id, .. }
+            | CadenceType::Contract { type_id, .. }
+            | CadenceType::StructInterface { type_id, .. }
+            | CadenceType::ResourceInterface { type_id, .. }
+            | CadenceType::ContractInterface { type_id, .. }
+            | CadenceType::Function { type_id, .. }
+            | CadenceType::Enum { type_id, .. } => type_id.clone(),
+        }
+    }
+
+    /// Parses a Cadence type-id string such as `{String: [UInt64]}`,
+    /// `&A.0x1.Foo.Bar` or `Int?` into a CadenceType.
+    ///
+    /// Identifiers that don't name a built-in type are treated as composite
+    /// types and produce a `Struct` carrying the identifier as its `type_id`.
+    /// Types nested more than 128 levels deep are rejected.
+    pub fn parse(s: &str) -> Result<CadenceType> {
+        let mut parser = TypeParser {
+            input: s,
+            pos: 0,
+            depth: 0,
+        };
+        let parsed = parser.parse_type()?;
+        parser.skip_whitespace();
+        if parser.pos != s.len() {
+            return Err(parser.error("unexpected trailing input"));
+        }
+        Ok(parsed)
+    }
+}
+
+/// Prints the canonical type-id, the same string as [`CadenceType::type_id`]
+impl fmt::Display for CadenceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.type_id())
+    }
+}
+
+impl FieldType {
+    pub fn new(id: impl Into<String>, type_: CadenceType) -> Self {
+        FieldType {
+            id: id.into(),
+            type_,
+        }
+    }
+}
+
+impl ParameterType {
+    /// Builds an initializer or function parameter; `label` is the argument
+    /// label callers write, or `_` for none
+    pub fn new(label: impl Into<String>, id: impl Into<String>, type_: CadenceType) -> Self {
+        ParameterType {
+            label: label.into(),
+            id: id.into(),
+            type_,
+        }
+    }
+}
+
+// Maps the name of a built-in type without parameters to its CadenceType
+fn simple_type(name: &str) -> Option<CadenceType> {
+    let parsed = match name {
+        "Account" => CadenceType::Account,
+        "AccountCapabilityController" => CadenceType::AccountCapabilityController,
+        "AccountKey" => CadenceType::AccountKey,
+        "Address" => CadenceType::Address,
+        "AnyResource" => CadenceType::AnyResource,
+        "AnyResourceAttachment" => CadenceType::AnyResourceAttachment,
+        "AnyStruct" => CadenceType::AnyStruct,
+        "AnyStructAttachment" => CadenceType::AnyStructAttachment,
+        "Block" => CadenceType::Block,
+        "Bool" => CadenceType::Bool,
+        "CapabilityPath" => CadenceType::CapabilityPath,
+        "Character" => CadenceType::Character,
+        "DeployedContract" => CadenceType::DeployedContract,
+        "DeploymentResult" => CadenceType::DeploymentResult,
+        "Fix64" => CadenceType::Fix64,
+        "FixedPoint" => CadenceType::FixedPoint,
+        "FixedSizeUnsignedInteger" => CadenceType::FixedSizeUnsignedInteger,
+        "HashAlgorithm" => CadenceType::HashAlgorithm,
+        "HashableStruct" => CadenceType::HashableStruct,
+        "Int" => CadenceType::Int,
+        "Int8" => CadenceType::Int8,
+        "Int16" => CadenceType::Int16,
+        "Int32" => CadenceType::Int32,
+        "Int64" => CadenceType::Int64,
+        "Int128" => CadenceType::Int128,
+        "Int256" => CadenceType::Int256,
+        "Integer" => CadenceType::Integer,
+        "Never" => CadenceType::Never,
+        "Number" => CadenceType::Number,
+        "Path" => CadenceType::Path,
+        "PrivatePath" => CadenceType::PrivatePath,
+        "PublicKey" => CadenceType::PublicKey,
+        "PublicPath" => CadenceType::PublicPath,
+        "SignatureAlgorithm" => CadenceType::SignatureAlgorithm,
+        "SignedFixedPoint" => CadenceType::SignedFixedPoint,
+        "SignedInteger" => CadenceType::SignedInteger,
+        "SignedNumber" => CadenceType::SignedNumber,
+        "StorageCapabilityController" => CadenceType::StorageCapabilityController,
+        "StoragePath" => CadenceType::StoragePath,
+        "String" => CadenceType::String,
+        "Type" => CadenceType::Type,
+        "UFix64" => CadenceType::UFix64,
+        "UInt" => CadenceType::UInt,
+        "UInt8" => CadenceType::UInt8,
+        "UInt16" => CadenceType::UInt16,
+        "UInt32" => CadenceType::UInt32,
+        "UInt64" => CadenceType::UInt64,
+        "UInt128" => CadenceType::UInt128,
+        "UInt256" => CadenceType::UInt256,
+        "Void" => CadenceType::Void,
+        "Word8" => CadenceType::Word8,
+        "Word16" => CadenceType::Word16,
+        "Word32" => CadenceType::Word32,
+        "Word64" => CadenceType::Word64,
+        "Word128" => CadenceType::Word128,
+        "Word256" => CadenceType::Word256,
+        _ => return None,
+    };
+    Some(parsed)
+}
+
+// Nesting limit for type-ids, so a hostile string like `[[[[...` fails
+// with an error instead of overflowing the stack
+const MAX_TYPE_DEPTH: usize = 128;
+
+// Recursive-descent parser over the type-id notation
+struct TypeParser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl TypeParser<'_> {
+    fn error(&self, msg: &str) -> Error {
+        Error::Custom(format!(
+            "Invalid Cadence type-id {:?} at position {}: {}",
+            self.input, self.pos, msg
+        ))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c)))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<&str> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a type"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn parse_type(&mut self) -> Result<CadenceType> {
+        let mut parsed = self.parse_primary()?;
+        while self.eat('?') {
+            parsed = CadenceType::Optional {
+                type_: Box::new(parsed),
+            };
+        }
+        Ok(parsed)
+    }
+
+    fn parse_primary(&mut self) -> Result<CadenceType> {
+        if self.depth == MAX_TYPE_DEPTH {
+            return Err(self.error("type is nested too deeply"));
+        }
+        self.depth += 1;
+        let parsed = self.parse_primary_inner();
+        self.depth -= 1;
+        parsed
+    }
+
+    fn parse_primary_inner(&mut self) -> Result<CadenceType> {
+        match self.peek() {
+            Some('[') => self.parse_array(),
+            Some('{') => self.parse_braced(),
+            Some('&') => self.parse_reference(Authorization::Unauthorized { entitlements: None }),
+            Some('(') => {
+                self.expect('(')?;
+                let inner = self.parse_type()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Some(_) => {
+                let start = self.pos;
+                let name = self.identifier()?;
+                match name {
+                    "auth" => {
+                        let authorization = self.parse_authorization()?;
+                        self.parse_reference(authorization)
+                    }
+                    "Capability" => Ok(CadenceType::Capability {
+                        type_: Box::new(self.parse_type_argument()?),
+                    }),
+                    "InclusiveRange" => Ok(CadenceType::InclusiveRange {
+                        element: Box::new(self.parse_type_argument()?),
+                    }),
+                    _ => Ok(simple_type(name).unwrap_or_else(|| CadenceType::Struct {
+                        type_: String::new(),
+                        type_id: self.input[start..self.pos].to_string(),
+                        initializers: Vec::new(),
+                        fields: Vec::new(),
+                    })),
+                }
+            }
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_type_argument(&mut self) -> Result<CadenceType> {
+        self.expect('<')?;
+        let inner = self.parse_type()?;
+        self.expect('>')?;
+        Ok(inner)
+    }
+
+    fn parse_array(&mut self) -> Result<CadenceType> {
+        self.expect('[')?;
+        let element = Box::new(self.parse_type()?);
+        if self.eat(';') {
+            let size = self
+                .identifier()?
+                .parse()
+                .map_err(|_| self.error("expected an array size"))?;
+            self.expect(']')?;
+            return Ok(CadenceType::ConstantSizedArray {
+                type_: element,
+                size,
+            });
+        }
+        self.expect(']')?;
+        Ok(CadenceType::VariableSizedArray { type_: element })
+    }
+
+    // Either a dictionary `{K: V}` or an intersection `{A, B}`
+    fn parse_braced(&mut self) -> Result<CadenceType> {
+        let start = self.pos;
+        self.expect('{')?;
+        let first = self.parse_type()?;
+        if self.eat(':') {
+            let value = self.parse_type()?;
+            self.expect('}')?;
+            return Ok(CadenceType::Dictionary {
+                key: Box::new(first),
+                value: Box::new(value),
+            });
+        }
+        let mut types = vec![first];
+        while self.eat(',') {
+            types.push(self.parse_type()?);
+        }
+        self.expect('}')?;
+        Ok(CadenceType::Intersection {
+            type_id: self.input[start..self.pos].trim().to_string(),
+            types,
+        })
+    }
+
+    fn parse_authorization(&mut self) -> Result<Authorization> {
+        self.expect('(')?;
+        let mut mapping = false;
+        let mut separator = None;
+        let mut entitlements = Vec::new();
+        loop {
+            let name = self.identifier()?;
+            if name == "mapping" && entitlements.is_empty() && !mapping {
+                mapping = true;
+                continue;
+            }
+            let type_id = name.to_string();
+            entitlements.push(if mapping {
+                Entitlement::EntitlementMap { type_id }
+            } else {
+                Entitlement::Plain { type_id }
+            });
+            let next = if self.eat(',') {
+                ','
+            } else if self.eat('|') {
+                '|'
+            } else {
+                break;
+            };
+            if separator.is_some_and(|seen| seen != next) {
+                return Err(self.error("cannot mix ',' and '|' in an authorization"));
+            }
+            separator = Some(next);
+        }
+        self.expect(')')?;
+        Ok(if mapping {
+            Authorization::EntitlementMapAuthorization { entitlements }
+        } else if separator == Some('|') {
+            Authorization::EntitlementDisjunctionSet { entitlements }
+        } else {
+            Authorization::EntitlementConjunctionSet { entitlements }
+        })
+    }
+
+    fn parse_reference(&mut self, authorization: Authorization) -> Result<CadenceType> {
+        self.expect('&')?;
+        Ok(CadenceType::Reference {
+            authorization,
+            type_: Box::new(self.parse_primary()?),
+        })
+    }
+}
+
+// Renders the `auth(...)` prefix of a reference type, or None for plain references
+fn authorization_type_id(authorization: &Authorization) -> Option<String> {
+    let (entitlements, separator) = match authorization {
+        Authorization::Unauthorized { .. } => return None,
+        Authorization::EntitlementMapAuthorization { entitlements } => {
+            let names: Vec<&str> = entitlements.iter().map(entitlement_type_id).collect();
+            return Some(format!("auth(mapping {})", names.join(", ")));
+        }
+        Authorization::EntitlementConjunctionSet { entitlements } => (entitlements, ", "),
+        Authorization::EntitlementDisjunctionSet { entitlements } => (entitlements, " | "),
+    };
+    let names: Vec<&str> = entitlements.iter().map(entitlement_type_id).collect();
+    Some(format!("auth({})", names.join(separator)))
+}
+
+fn entitlement_type_id(entitlement: &Entitlement) -> &str {
+    match entitlement {
+        Entitlement::Plain { type_id } | Entitlement::EntitlementMap { type_id } => type_id,
+    }
+}
@@ -0,0 +1,692 @@
+// src/value.rs
+
+// This file contains inherent helpers on CadenceValue for inspecting and
+// building values without going through the conversion traits
+
+use crate::{
+    CadenceType, CadenceValue, CapabilityValue, CompositeField, CompositeValue, DictionaryEntry,
+    Error, Name, Result,
+};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use core::hash::{Hash, Hasher};
+use core::mem::size_of;
+
+// Fix64 and UFix64 values have exactly 8 decimal places
+const FIXED_POINT_DECIMALS: usize = 8;
+pub(crate) const FIXED_POINT_SCALE: i128 = 100_000_000;
+
+impl CadenceValue {
+    /// Returns true for `Resource` values
+    pub fn is_resource(&self) -> bool {
+        matches!(self, CadenceValue::Resource { .. })
+    }
+
+    /// Returns true for `Struct` values
+    pub fn is_struct(&self) -> bool {
+        matches!(self, CadenceValue::Struct { .. })
+    }
+
+    /// Returns true for `Event` values
+    pub fn is_event(&self) -> bool {
+        matches!(self, CadenceValue::Event { .. })
+    }
+
+    /// Returns true for `Contract` values
+    pub fn is_contract(&self) -> bool {
+        matches!(self, CadenceValue::Contract { .. })
+    }
+
+    /// Returns true for `Enum` values
+    pub fn is_enum(&self) -> bool {
+        matches!(self, CadenceValue::Enum { .. })
+    }
+
+    /// Returns true for any value carrying a `CompositeValue`
+    pub fn is_composite(&self) -> bool {
+        self.composite_id().is_some()
+    }
+
+    /// Returns the fully qualified type identifier of a composite value
+    pub fn composite_id(&self) -> Option<&str> {
+        match self {
+            CadenceValue::Struct { value }
+            | CadenceValue::Resource { value }
+            | CadenceValue::Event { value }
+            | CadenceValue::Contract { value }
+            | CadenceValue::Enum { value } => Some(&value.id),
+            _ => None,
+        }
+    }
+
+    /// Looks up a nested value by a `/`-separated path such as `"metadata/tags/0"`.
+    ///
+    /// Names select composite fields and integers select array elements.
+    /// Returns None if any segment is missing or doesn't fit the value it indexes.
+    pub fn get(&self, path: &str) -> Option<&CadenceValue> {
+        let mut current = self;
+        for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+            current = match current {
+                CadenceValue::Array { value } => value.get(segment.parse::<usize>().ok()?)?,
+                CadenceValue::Struct { value }
+                | CadenceValue::Resource { value }
+                | CadenceValue::Event { value }
+                | CadenceValue::Contract { value }
+                | CadenceValue::Enum { value } => {
+                    &value
+                        .fields
+                        .iter()
+                        .find(|field| field.name == segment)?
+                        .value
+                }
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    /// Merges the fields of another composite into this one: fields of `other`
+    /// replace fields of the same name and new ones are appended in order.
+    ///
+    /// Both values must be composites of the same kind and type id.
+    pub fn merge(&mut self, other: &CadenceValue) -> Result<()> {
+        let (target, source) = match (&mut *self, other) {
+            (CadenceValue::Struct { value: target }, CadenceValue::Struct { value: source })
+            | (
+                CadenceValue::Resource { value: target },
+                CadenceValue::Resource { value: source },
+            )
+            | (CadenceValue::Event { value: target }, CadenceValue::Event { value: source })
+            | (
+                CadenceValue::Contract { value: target },
+                CadenceValue::Contract { value: source },
+            )
+            | (CadenceValue::Enum { value: target }, CadenceValue::Enum { value: source }) => {
+                (target, source)
+            }
+            (_, source) => {
+                return Err(Error::TypeMismatch {
+                    expected: "composite of the same kind".to_string(),
+                    got: format!("{:?}", source),
+                });
+            }
+        };
+        if target.id != source.id {
+            return Err(Error::TypeMismatch {
+                expected: target.id.to_string(),
+                got: source.id.to_string(),
+            });
+        }
+
+        for field in &source.fields {
+            match target
+                .fields
+                .iter_mut()
+                .find(|existing| existing.name == field.name)
+            {
+                Some(existing) => existing.value = field.value.clone(),
+                None => target.fields.push(field.clone()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the first field called `name` from a composite and returns it,
+    /// keeping the order of the remaining fields.
+    ///
+    /// Returns None for non-composites or if there is no such field.
+    pub fn take_field(&mut self, name: &str) -> Option<CompositeField> {
+        match self {
+            CadenceValue::Struct { value }
+            | CadenceValue::Resource { value }
+            | CadenceValue::Event { value }
+            | CadenceValue::Contract { value }
+            | CadenceValue::Enum { value } => {
+                let index = value.fields.iter().position(|field| field.name == name)?;
+                Some(value.fields.remove(index))
+            }
+            _ => None,
+        }
+    }
+
+    /// Builds an `Enum` value holding a single case, identified by its raw
+    /// value as Cadence encodes enums, e.g. `enum_case("A.0x1.Color", 2u8)`
+    pub fn enum_case(type_id: impl Into<Name>, raw_value: impl Into<CadenceValue>) -> CadenceValue {
+        CadenceValue::Enum {
+            value: CompositeValue {
+                id: type_id.into(),
+                fields: vec![CompositeField {
+                    name: "rawValue".into(),
+                    value: raw_value.into(),
+                }],
+            },
+        }
+    }
+
+    /// Returns the `rawValue` field of an `Enum` value, which identifies the case
+    pub fn enum_raw_value(&self) -> Option<&CadenceValue> {
+        match self {
+            CadenceValue::Enum { value } => value
+                .fields
+                .iter()
+                .find(|field| field.name == "rawValue")
+                .map(|field| &field.value),
+            _ => None,
+        }
+    }
+
+    /// Returns the type id of an `Enum` value. Cadence-JSON doesn't name the
+    /// case itself, so use [`CadenceValue::enum_raw_value`] to tell cases apart.
+    pub fn enum_type_id(&self) -> Option<&str> {
+        match self {
+            CadenceValue::Enum { value } => Some(&value.id),
+            _ => None,
+        }
+    }
+
+    /// Peels every `Optional` layer off this value, returning the first
+    /// non-optional value inside, or the innermost `nil` if there is none.
+    ///
+    /// This intentionally discards how deeply the value was nested, so
+    /// `Optional(Optional(nil))` and `Optional(nil)` flatten to the same `nil`.
+    /// Non-optional values are returned as they are.
+    pub fn flatten_optional(&self) -> &CadenceValue {
+        let mut current = self;
+        while let CadenceValue::Optional { value: Some(inner) } = current {
+            current = inner;
+        }
+        current
+    }
+
+    /// Builds a `Capability` value from its controller id, the address of the
+    /// account it was issued by, and the reference type it borrows as
+    pub fn capability(
+        id: impl Into<String>,
+        address: impl Into<String>,
+        borrow_type: CadenceType,
+    ) -> CadenceValue {
+        CadenceValue::Capability {
+            value: CapabilityValue {
+                id: id.into(),
+                address: address.into(),
+                borrow_type,
+            },
+        }
+    }
+
+    /// Returns the `(id, address, borrow_type)` of a `Capability` value
+    pub fn as_capability(&self) -> Option<(&str, &str, &CadenceType)> {
+        match self {
+            CadenceValue::Capability { value } => {
+                Some((&value.id, &value.address, &value.borrow_type))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the entries of a `Dictionary` value
+    pub fn as_dictionary(&self) -> Option<&[DictionaryEntry]> {
+        match self {
+            CadenceValue::Dictionary { value } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Looks up the value stored under `key` in a `Dictionary` value.
+    ///
+    /// Keys are compared in canonical form (see [`CadenceValue::canonicalize`]),
+    /// so `1.0` and `1.00000000` find the same `UFix64` entry. This is a linear
+    /// search that clones and canonicalizes every entry key it passes, so each
+    /// lookup costs O(n) allocations; convert to a map for repeated lookups.
+    pub fn dictionary_get(&self, key: &CadenceValue) -> Option<&CadenceValue> {
+        let key = key.canonical();
+        self.as_dictionary()?
+            .iter()
+            .find(|entry| entry.key.canonical() == key)
+            .map(|entry| &entry.value)
+    }
+
+    /// Checks that no dictionary in this value, at any depth, has two keys that
+    /// are equal in canonical form, as Cadence requires.
+    ///
+    /// Parsing doesn't check this by default; set
+    /// [`ParseOptions::unique_keys`](crate::ParseOptions::unique_keys) to have
+    /// [`from_str_value_with`](crate::from_str_value_with) and friends call it.
+    pub fn check_dictionary_keys(&self) -> Result<()> {
+        let mut result = Ok(());
+        self.walk(&mut |value| {
+            if result.is_err() {
+                return;
+            }
+            if let CadenceValue::Dictionary { value: entries } = value {
+                result = check_unique_keys(entries);
+            }
+        });
+        result
+    }
+
+    /// Visits this value and every value nested inside it, depth-first, parents
+    /// before children: optional contents, array elements, dictionary keys and
+    /// values, composite fields and range bounds.
+    pub fn walk(&self, f: &mut dyn FnMut(&CadenceValue)) {
+        f(self);
+        match self {
+            CadenceValue::Optional { value: Some(inner) } => inner.walk(f),
+            CadenceValue::Array { value } => value.iter().for_each(|item| item.walk(f)),
+            CadenceValue::Dictionary { value } => {
+                for entry in value {
+                    entry.key.walk(f);
+                    entry.value.walk(f);
+                }
+            }
+            CadenceValue::Struct { value }
+            | CadenceValue::Resource { value }
+            | CadenceValue::Event { value }
+            | CadenceValue::Contract { value }
+            | CadenceValue::Enum { value } => {
+                value.fields.iter().for_each(|field| field.value.walk(f))
+            }
+            CadenceValue::InclusiveRange { value } => {
+                value.start.walk(f);
+                value.end.walk(f);
+                value.step.walk(f);
+            }
+            _ => {}
+        }
+    }
+
+    /// Mutable counterpart of [`CadenceValue::walk`]
+    pub fn walk_mut(&mut self, f: &mut dyn FnMut(&mut CadenceValue)) {
+        f(self);
+        match self {
+            CadenceValue::Optional { value: Some(inner) } => inner.walk_mut(f),
+            CadenceValue::Array { value } => value.iter_mut().for_each(|item| item.walk_mut(f)),
+            CadenceValue::Dictionary { value } => {
+                for entry in value {
+                    entry.key.walk_mut(f);
+                    entry.value.walk_mut(f);
+                }
+            }
+            CadenceValue::Struct { value }
+            | CadenceValue::Resource { value }
+            | CadenceValue::Event { value }
+            | CadenceValue::Contract { value }
+            | CadenceValue::Enum { value } => value
+                .fields
+                .iter_mut()
+                .for_each(|field| field.value.walk_mut(f)),
+            CadenceValue::InclusiveRange { value } => {
+                value.start.walk_mut(f);
+                value.end.walk_mut(f);
+                value.step.walk_mut(f);
+            }
+            _ => {}
+        }
+    }
+
+    /// Estimates the memory used by this value in bytes: the value itself plus
+    /// every heap allocation it owns, including nested strings, arrays,
+    /// dictionaries and composites.
+    ///
+    /// This is an approximation meant for budgeting and caching decisions (types
+    /// are counted by the length of their type id), but it only grows as the
+    /// value gains content.
+    pub fn deep_size(&self) -> usize {
+        let mut size = size_of::<CadenceValue>();
+        self.walk(&mut |value| size += value.heap_size());
+        size
+    }
+
+    // The heap bytes owned directly by this value, excluding those of nested
+    // values, which `walk` visits separately
+    fn heap_size(&self) -> usize {
+        match self {
+            CadenceValue::Void {} | CadenceValue::Bool { .. } => 0,
+            CadenceValue::Optional { value } => {
+                value.as_ref().map_or(0, |_| size_of::<CadenceValue>())
+            }
+            CadenceValue::String { value }
+            | CadenceValue::Character { value }
+            | CadenceValue::Address { value }
+            | CadenceValue::Int { value }
+            | CadenceValue::Int8 { value }
+            | CadenceValue::Int16 { value }
+            | CadenceValue::Int32 { value }
+            | CadenceValue::Int64 { value }
+            | CadenceValue::Int128 { value }
+            | CadenceValue::Int256 { value }
+            | CadenceValue::UInt { value }
+            | CadenceValue::UInt8 { value }
+            | CadenceValue::UInt16 { value }
+            | CadenceValue::UInt32 { value }
+            | CadenceValue::UInt64 { value }
+            | CadenceValue::UInt128 { value }
+            | CadenceValue::UInt256 { value }
+            | CadenceValue::Word8 { value }
+            | CadenceValue::Word16 { value }
+            | CadenceValue::Word32 { value }
+            | CadenceValue::Word64 { value }
+            | CadenceValue::Word128 { value }
+            | CadenceValue::Word256 { value }
+            | CadenceValue::Fix64 { value }
+            | CadenceValue::UFix64 { value } => value.capacity(),
+            CadenceValue::Array { value } => value.capacity() * size_of::<CadenceValue>(),
+            CadenceValue::Dictionary { value } => value.capacity() * size_of::<DictionaryEntry>(),
+            CadenceValue::Struct { value }
+            | CadenceValue::Resource { value }
+            | CadenceValue::Event { value }
+            | CadenceValue::Contract { value }
+            | CadenceValue::Enum { value } => {
+                value.id.capacity()
+                    + value.fields.capacity() * size_of::<CompositeField>()
+                    + value
+                        .fields
+                        .iter()
+                        .map(|field| field.name.capacity())
+                        .sum::<usize>()
+            }
+            CadenceValue::Path { value } => value.identifier.capacity(),
+            CadenceValue::Type { value } => value.static_type.type_id().len(),
+            CadenceValue::InclusiveRange { .. } => 3 * size_of::<CadenceValue>(),
+            CadenceValue::Capability { value } => {
+                value.id.capacity() + value.address.capacity() + value.borrow_type.type_id().len()
+            }
+            CadenceValue::Function { value } => value.function_type.type_id().len(),
+        }
+    }
+
+    /// Recursively sorts dictionary entries by their encoded key and composite
+    /// fields by name, and rewrites numbers as [`CadenceValue::normalize_numbers`]
+    /// does, so that semantically equal values compare equal.
+    pub fn canonicalize(&mut self) {
+        self.normalize_number();
+        match self {
+            CadenceValue::Optional { value: Some(inner) } => inner.canonicalize(),
+            CadenceValue::Array { value } => value.iter_mut().for_each(CadenceValue::canonicalize),
+            CadenceValue::Dictionary { value } => {
+                for entry in value.iter_mut() {
+                    entry.key.canonicalize();
+                    entry.value.canonicalize();
+                }
+                value.sort_by_cached_key(|entry| {
+                    serde_json::to_string(&entry.key).unwrap_or_default()
+                });
+            }
+            CadenceValue::Struct { value }
+            | CadenceValue::Resource { value }
+            | CadenceValue::Event { value }
+            | CadenceValue::Contract { value }
+            | CadenceValue::Enum { value } => {
+                for field in value.fields.iter_mut() {
+                    field.value.canonicalize();
+                }
+                value.fields.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+            CadenceValue::InclusiveRange { value } => {
+                value.start.canonicalize();
+                value.end.canonicalize();
+                value.step.canonicalize();
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns a canonicalized copy of this value, see [`CadenceValue::canonicalize`]
+    pub fn canonical(&self) -> CadenceValue {
+        let mut canonical = self.clone();
+        canonical.canonicalize();
+        canonical
+    }
+
+    /// Recursively rewrites integer and fixed-point payloads in canonical form:
+    /// no leading `+` or zeros, `-0` as `0`, and fixed-point numbers with
+    /// exactly 8 decimals, so `"+007"` becomes `"7"` and `"1.5"` becomes
+    /// `"1.50000000"`. Payloads that aren't valid numbers are left as they are.
+    pub fn normalize_numbers(&mut self) {
+        self.walk_mut(&mut CadenceValue::normalize_number);
+    }
+
+    // Normalizes this value's own number payload, without recursing
+    fn normalize_number(&mut self) {
+        match self {
+            CadenceValue::Fix64 { value } | CadenceValue::UFix64 { value } => {
+                if let Some(scaled) = parse_fixed_point(value) {
+                    *value = format_fixed_point(scaled);
+                }
+            }
+            CadenceValue::Int { value }
+            | CadenceValue::Int8 { value }
+            | CadenceValue::Int16 { value }
+            | CadenceValue::Int32 { value }
+            | CadenceValue::Int64 { value }
+            | CadenceValue::Int128 { value }
+            | CadenceValue::Int256 { value }
+            | CadenceValue::UInt { value }
+            | CadenceValue::UInt8 { value }
+            | CadenceValue::UInt16 { value }
+            | CadenceValue::UInt32 { value }
+            | CadenceValue::UInt64 { value }
+            | CadenceValue::UInt128 { value }
+            | CadenceValue::UInt256 { value }
+            | CadenceValue::Word8 { value }
+            | CadenceValue::Word16 { value }
+            | CadenceValue::Word32 { value }
+            | CadenceValue::Word64 { value }
+            | CadenceValue::Word128 { value }
+            | CadenceValue::Word256 { value } => {
+                if let Some(normalized) = normalize_integer(value) {
+                    *value = normalized;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the value of a `Fix64` or `UFix64` scaled by 10^8, which is how
+    /// Cadence represents fixed-point numbers internally.
+    ///
+    /// Unlike going through `f64`, this never loses precision.
+    pub fn as_fixed_point(&self) -> Option<i128> {
+        match self {
+            CadenceValue::Fix64 { value } | CadenceValue::UFix64 { value } => {
+                parse_fixed_point(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns any integer value (`Int8` through `Word256`) as an `i128`.
+    ///
+    /// Fails with `Error::TypeMismatch` for fixed-point and non-numeric values,
+    /// and with `Error::Custom` if the number doesn't fit in an `i128`.
+    pub fn to_i128(&self) -> Result<i128> {
+        self.integer_digits()?
+            .parse()
+            .map_err(|e| Error::Custom(format!("Failed to convert {:?} to i128: {}", self, e)))
+    }
+
+    /// Returns any non-negative integer value as a `u128`, see [`CadenceValue::to_i128`]
+    pub fn to_u128(&self) -> Result<u128> {
+        self.integer_digits()?
+            .parse()
+            .map_err(|e| Error::Custom(format!("Failed to convert {:?} to u128: {}", self, e)))
+    }
+
+    // The decimal digits of an integer value of any width
+    fn integer_digits(&self) -> Result<&str> {
+        match self {
+            CadenceValue::Int { value }
+            | CadenceValue::Int8 { value }
+            | CadenceValue::Int16 { value }
+            | CadenceValue::Int32 { value }
+            | CadenceValue::Int64 { value }
+            | CadenceValue::Int128 { value }
+            | CadenceValue::Int256 { value }
+            | CadenceValue::UInt { value }
+            | CadenceValue::UInt8 { value }
+            | CadenceValue::UInt16 { value }
+            | CadenceValue::UInt32 { value }
+            | CadenceValue::UInt64 { value }
+            | CadenceValue::UInt128 { value }
+            | CadenceValue::UInt256 { value }
+            | CadenceValue::Word8 { value }
+            | CadenceValue::Word16 { value }
+            | CadenceValue::Word32 { value }
+            | CadenceValue::Word64 { value }
+            | CadenceValue::Word128 { value }
+            | CadenceValue::Word256 { value } => Ok(value),
+            _ => Err(Error::TypeMismatch {
+                expected: "Integer".to_string(),
+                got: format!("{:?}", self),
+            }),
+        }
+    }
+
+    /// Builds a `Fix64` or `UFix64`, whichever `ty` names, from an integer
+    /// scaled by 10^8.
+    ///
+    /// Fails if `ty` is any other type or `scaled` is out of range for it.
+    pub fn fixed_point_from_scaled(scaled: i128, ty: &CadenceType) -> Result<CadenceValue> {
+        let fits = match ty {
+            CadenceType::Fix64 => i64::try_from(scaled).is_ok(),
+            CadenceType::UFix64 => u64::try_from(scaled).is_ok(),
+            _ => {
+                return Err(Error::TypeMismatch {
+                    expected: "Fix64 or UFix64".to_string(),
+                    got: ty.type_id(),
+                });
+            }
+        };
+        let value = format_fixed_point(scaled);
+        if !fits {
+            return Err(Error::InvalidCadenceValue(format!(
+                "{} is out of range for {}",
+                value, ty
+            )));
+        }
+        Ok(match ty {
+            CadenceType::Fix64 => CadenceValue::Fix64 { value },
+            _ => CadenceValue::UFix64 { value },
+        })
+    }
+}
+
+/// A CadenceValue that compares and hashes by its canonical form, so values
+/// that differ only in dictionary or field order or number formatting are
+/// equal. Use it to deduplicate values in a `HashSet` or key a `HashMap`.
+///
+/// The value is canonicalized once, on construction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canonical(CadenceValue);
+
+impl Canonical {
+    pub fn new(mut value: CadenceValue) -> Self {
+        value.canonicalize();
+        Canonical(value)
+    }
+
+    /// Returns the canonicalized value
+    pub fn value(&self) -> &CadenceValue {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> CadenceValue {
+        self.0
+    }
+}
+
+impl From<CadenceValue> for Canonical {
+    fn from(value: CadenceValue) -> Self {
+        Canonical::new(value)
+    }
+}
+
+impl Hash for Canonical {
+    // Equal canonical values encode to the same JSON
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        serde_json::to_string(&self.0)
+            .unwrap_or_default()
+            .hash(state);
+    }
+}
+
+// Formats a value scaled by 10^8 as a decimal string with exactly 8 decimals
+pub(crate) fn format_fixed_point(scaled: i128) -> String {
+    let sign = if scaled < 0 { "-" } else { "" };
+    let magnitude = scaled.unsigned_abs();
+    let scale = FIXED_POINT_SCALE as u128;
+    format!(
+        "{}{}.{:0width$}",
+        sign,
+        magnitude / scale,
+        magnitude % scale,
+        width = FIXED_POINT_DECIMALS
+    )
+}
+
+// Strips the sign and leading zeros from integer digits of any width, or
+// returns None if they aren't an integer
+fn normalize_integer(value: &str) -> Option<String> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let digits = digits.trim_start_matches('0');
+    Some(match (negative, digits) {
+        (_, "") => "0".to_string(),
+        (true, digits) => format!("-{}", digits),
+        (false, digits) => digits.to_string(),
+    })
+}
+
+// Parses a decimal string with at most 8 fractional digits into its scaled form.
+// A leading `+` is accepted like it is for integers, but as in Cadence literals
+// a `.` must have digits on both sides, so `1.` and `.5` are rejected.
+pub(crate) fn parse_fixed_point(value: &str) -> Option<i128> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((_, "")) => return None,
+        Some(parts) => parts,
+        None => (digits, ""),
+    };
+    if integer.is_empty()
+        || fraction.len() > FIXED_POINT_DECIMALS
+        || !integer.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let integer: i128 = integer.parse().ok()?;
+    let fraction: i128 = if fraction.is_empty() {
+        0
+    } else {
+        let padding = FIXED_POINT_DECIMALS - fraction.len();
+        fraction.parse::<i128>().ok()? * 10i128.pow(padding as u32)
+    };
+    let scaled = integer
+        .checked_mul(FIXED_POINT_SCALE)?
+        .checked_add(fraction)?;
+    Some(if negative { -scaled } else { scaled })
+}
+
+// Canonical keys are compared by their JSON encoding, which keeps this
+// O(n log n) without needing `Ord` on CadenceValue
+fn check_unique_keys(entries: &[DictionaryEntry]) -> Result<()> {
+    let mut seen = BTreeSet::new();
+    for entry in entries {
+        if !seen.insert(serde_json::to_string(&entry.key.canonical())?) {
+            return Err(Error::InvalidCadenceValue(
+                "duplicate dictionary key".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
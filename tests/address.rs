@@ -0,0 +1,91 @@
+// tests/address.rs
+//
+// Decoding Address values into their bytes and into typed map keys
+
+use serde_cadence::CadenceValue;
+#[cfg(feature = "std")]
+use serde_cadence::{Address, FromCadenceValue, from_str_value};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+fn address(value: &str) -> CadenceValue {
+    CadenceValue::Address {
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn addresses_decode_into_big_endian_bytes() {
+    assert_eq!(
+        address("0xf8d6e0586b0a20c7").as_address_bytes(),
+        Some([0xf8, 0xd6, 0xe0, 0x58, 0x6b, 0x0a, 0x20, 0xc7])
+    );
+    assert_eq!(
+        address("0x1").as_address_bytes(),
+        Some([0, 0, 0, 0, 0, 0, 0, 1])
+    );
+    assert_eq!(
+        address("0x0000000000000001").as_address_bytes(),
+        address("1").as_address_bytes()
+    );
+    assert_eq!(
+        address("0xABCDEF").as_address_bytes(),
+        Some([0, 0, 0, 0, 0, 0xab, 0xcd, 0xef])
+    );
+}
+
+#[test]
+fn malformed_addresses_have_no_bytes() {
+    for malformed in ["", "0x", "0xzz", "0x10000000000000000", "0x+1", "0x 1"] {
+        assert_eq!(address(malformed).as_address_bytes(), None, "{}", malformed);
+    }
+    let string = CadenceValue::String {
+        value: "0x1".to_string(),
+    };
+    assert_eq!(string.as_address_bytes(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn address_keyed_dictionaries_decode_into_hash_maps() {
+    let value = from_str_value(
+        r#"{"type":"Dictionary","value":[
+            {"key":{"type":"Address","value":"0x1234567890abcdef"},"value":{"type":"String","value":"Alice's Account"}},
+            {"key":{"type":"Address","value":"0xf1e2d3c4b5a69780"},"value":{"type":"String","value":"Bob's Account"}},
+            {"key":{"type":"Address","value":"0xa1b2c3d4e5f67890"},"value":{"type":"String","value":"Charlie's Account"}}
+        ]}"#,
+    )
+    .unwrap();
+    let accounts = HashMap::<Address, String>::from_cadence_value(&value).unwrap();
+    assert_eq!(accounts.len(), 3);
+    let bob: Address = "0xf1e2d3c4b5a69780".parse().unwrap();
+    assert_eq!(accounts[&bob], "Bob's Account");
+    assert_eq!(
+        accounts[&Address::from(0xa1b2c3d4e5f67890)],
+        "Charlie's Account"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn short_and_padded_addresses_are_the_same_key() {
+    let short: Address = "0x1".parse().unwrap();
+    let padded: Address = "0x0000000000000001".parse().unwrap();
+    assert_eq!(short, padded);
+    assert_eq!(short.to_string(), "0x0000000000000001");
+
+    let value = from_str_value(
+        r#"{"type":"Dictionary","value":[
+            {"key":{"type":"Address","value":"0x1"},"value":{"type":"String","value":"service"}}
+        ]}"#,
+    )
+    .unwrap();
+    let accounts = HashMap::<Address, String>::from_cadence_value(&value).unwrap();
+    assert_eq!(accounts[&padded], "service");
+
+    let mut keys = HashMap::new();
+    keys.insert(short, 1);
+    keys.insert(padded, 2);
+    assert_eq!(keys.len(), 1);
+    assert_eq!(serde_json::from_str::<Address>(r#""0x1""#).unwrap(), padded);
+}
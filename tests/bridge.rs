@@ -0,0 +1,101 @@
+// tests/bridge.rs
+//
+// Plain serde types going through SerdeBridge
+
+#![cfg(feature = "serde-bridge")]
+
+use serde::{Deserialize, Serialize};
+use serde_cadence::{CadenceValue, Error, FromCadenceValue, SerdeBridge, ToCadenceValue};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    retries: u8,
+    tags: Vec<String>,
+}
+
+fn config() -> Config {
+    Config {
+        name: "node".to_string(),
+        retries: 3,
+        tags: vec!["a".to_string()],
+    }
+}
+
+#[test]
+fn structs_become_string_keyed_dictionaries() {
+    let value = SerdeBridge(config()).to_cadence_value().unwrap();
+    let CadenceValue::Dictionary { value: entries } = &value else {
+        panic!("expected a dictionary, got {:?}", value);
+    };
+    assert_eq!(entries.len(), 3);
+    assert!(
+        entries
+            .iter()
+            .all(|entry| matches!(entry.key, CadenceValue::String { .. }))
+    );
+
+    let decoded = SerdeBridge::<Config>::from_cadence_value(&value).unwrap();
+    assert_eq!(decoded.into_inner(), config());
+}
+
+#[test]
+fn integer_keyed_maps_round_trip() {
+    let names = HashMap::from([(1u64, "Flow".to_string()), (u64::MAX, "Max".to_string())]);
+    let value = SerdeBridge(names.clone()).to_cadence_value().unwrap();
+    // JSON object keys are strings, so the keys arrive as Cadence Strings
+    let CadenceValue::Dictionary { value: entries } = &value else {
+        panic!("expected a dictionary, got {:?}", value);
+    };
+    assert!(
+        entries
+            .iter()
+            .all(|entry| matches!(entry.key, CadenceValue::String { .. }))
+    );
+
+    let decoded = SerdeBridge::<HashMap<u64, String>>::from_cadence_value(&value).unwrap();
+    assert_eq!(decoded.into_inner(), names);
+}
+
+fn config_value(retries: CadenceValue) -> CadenceValue {
+    serde_cadence::to_cadence_dictionary([
+        (
+            "name",
+            CadenceValue::String {
+                value: "node".to_string(),
+            },
+        ),
+        ("retries", retries),
+        ("tags", CadenceValue::Array { value: vec![] }),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn out_of_range_values_are_invalid() {
+    let value = config_value(CadenceValue::UInt8 {
+        value: "300".to_string(),
+    });
+    match SerdeBridge::<Config>::from_cadence_value(&value) {
+        Err(Error::InvalidCadenceValue(message)) => assert!(message.contains("300")),
+        other => panic!("expected an out-of-range error, got {:?}", other),
+    }
+}
+
+#[test]
+fn wrong_types_keep_the_serde_error() {
+    let value = config_value(CadenceValue::String {
+        value: "three".to_string(),
+    });
+    match SerdeBridge::<Config>::from_cadence_value(&value) {
+        Err(Error::SerdeJson(error)) => assert!(error.to_string().contains("invalid type")),
+        other => panic!("expected a serde_json error, got {:?}", other),
+    }
+
+    let missing = serde_cadence::to_cadence_dictionary([("name", "node")]).unwrap();
+    assert!(matches!(
+        SerdeBridge::<Config>::from_cadence_value(&missing),
+        Err(Error::SerdeJson(_))
+    ));
+}
@@ -0,0 +1,40 @@
+// tests/bytes.rs
+//
+// Binary data encoded as [UInt8] with a hex String fallback
+
+use serde_cadence::{Bytes, CadenceValue, FromCadenceValue, ToCadenceValue};
+
+#[test]
+fn bytes_encode_as_uint8_arrays() {
+    let bytes = Bytes(vec![0x00, 0xab, 0xff]);
+    let value = bytes.to_cadence_value().unwrap();
+    assert_eq!(value, vec![0u8, 0xab, 0xff].to_cadence_value().unwrap());
+    assert_eq!(Bytes::from_cadence_value(&value).unwrap(), bytes);
+}
+
+#[test]
+fn bytes_decode_from_hex_strings() {
+    let bytes = Bytes(vec![0x00, 0xab, 0xff]);
+    assert_eq!(bytes.to_hex(), "00abff");
+    assert_eq!(
+        bytes.to_hex_value(),
+        CadenceValue::String {
+            value: "00abff".to_string()
+        }
+    );
+    for hex in ["00abff", "0x00ABFF"] {
+        let value = CadenceValue::String {
+            value: hex.to_string(),
+        };
+        assert_eq!(Bytes::from_cadence_value(&value).unwrap(), bytes);
+    }
+    assert!(Bytes::from_hex("abc").is_err());
+    assert!(Bytes::from_hex("zz").is_err());
+}
+
+#[test]
+fn bytes_reject_non_uint8_elements() {
+    let value = vec![1u16].to_cadence_value().unwrap();
+    assert!(Bytes::from_cadence_value(&value).is_err());
+    assert!(Bytes::from_cadence_value(&CadenceValue::Bool { value: true }).is_err());
+}
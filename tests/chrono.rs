@@ -0,0 +1,37 @@
+// tests/chrono.rs
+//
+// Timestamps encoded as UFix64 seconds since the Unix epoch
+
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde_cadence::{CadenceValue, FromCadenceValue, ToCadenceValue};
+
+#[test]
+fn timestamps_round_trip_through_ufix64() {
+    let timestamp = Utc.timestamp_opt(1_700_000_000, 123_456_780).unwrap();
+    let value = timestamp.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::UFix64 {
+            value: "1700000000.12345678".to_string()
+        }
+    );
+    assert_eq!(
+        DateTime::<Utc>::from_cadence_value(&value).unwrap(),
+        timestamp
+    );
+}
+
+#[test]
+fn timestamps_outside_ufix64_are_rejected() {
+    let before_epoch = Utc.timestamp_opt(-1, 0).unwrap();
+    assert!(before_epoch.to_cadence_value().is_err());
+
+    // UFix64 tops out at 184467440737.09551615 seconds, in the year 7815
+    let last = Utc.timestamp_opt(184_467_440_737, 95_515_150).unwrap();
+    assert!(last.to_cadence_value().is_ok());
+    let past_max = Utc.timestamp_opt(184_467_440_738, 0).unwrap();
+    assert!(past_max.to_cadence_value().is_err());
+    assert!(DateTime::<Utc>::MAX_UTC.to_cadence_value().is_err());
+}
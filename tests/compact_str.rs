@@ -0,0 +1,61 @@
+// tests/compact_str.rs
+//
+// Composite ids and field names stored as CompactString
+
+#![cfg(feature = "compact-str")]
+
+use compact_str::CompactString;
+use serde_cadence::{CadenceValue, CompositeField, CompositeValue, Name, from_str_value};
+
+const EVENT_JSON: &str = r#"{"type":"Event","value":{"id":"A.0x1.Market.Listed","fields":[
+    {"name":"listingResourceID","value":{"type":"UInt64","value":"7"}},
+    {"name":"seller","value":{"type":"Address","value":"0x0000000000000001"}}
+]}}"#;
+
+#[test]
+fn names_parse_into_compact_strings() {
+    let value = from_str_value(EVENT_JSON).unwrap();
+    let CadenceValue::Event { value: composite } = &value else {
+        panic!("expected an event, got {:?}", value);
+    };
+    let id: &CompactString = &composite.id;
+    assert_eq!(id, "A.0x1.Market.Listed");
+    assert_eq!(composite.fields[0].name, "listingResourceID");
+    // Short names are stored inline
+    assert!(!composite.fields[1].name.is_heap_allocated());
+
+    assert_eq!(value.composite_id(), Some("A.0x1.Market.Listed"));
+    assert_eq!(
+        value.get("seller"),
+        Some(&CadenceValue::Address {
+            value: "0x0000000000000001".to_string()
+        })
+    );
+}
+
+#[test]
+fn parsed_and_built_composites_are_equal() {
+    let built = CadenceValue::Event {
+        value: CompositeValue {
+            id: Name::from("A.0x1.Market.Listed"),
+            fields: vec![
+                CompositeField {
+                    name: "listingResourceID".into(),
+                    value: CadenceValue::UInt64 {
+                        value: "7".to_string(),
+                    },
+                },
+                CompositeField {
+                    name: String::from("seller").into(),
+                    value: CadenceValue::Address {
+                        value: "0x0000000000000001".to_string(),
+                    },
+                },
+            ],
+        },
+    };
+    assert_eq!(from_str_value(EVENT_JSON).unwrap(), built);
+
+    let json = serde_json::to_string(&built).unwrap();
+    assert_eq!(from_str_value(&json).unwrap(), built);
+}
@@ -0,0 +1,359 @@
+// tests/conversion.rs
+//
+// Conversions between plain serde_json::Value trees and CadenceValue
+
+#[cfg(feature = "std")]
+use serde_cadence::{Bytes, EncodeOptions, Error, to_cadence_value, to_cadence_value_with};
+use serde_cadence::{
+    CadenceValue, ConversionOptions, FromCadenceValue, NumberMapping, ToCadenceValue,
+    cadence_value_to_value, value_to_cadence_value, value_to_cadence_value_with,
+};
+use serde_json::{Value, json};
+
+fn ufix64(value: &str) -> CadenceValue {
+    CadenceValue::UFix64 {
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn fixed_point_keeps_exact_decimals() {
+    let max = ufix64("184467440737.09551615");
+    assert_eq!(
+        cadence_value_to_value(&max).unwrap(),
+        Value::String("184467440737.09551615".to_string())
+    );
+
+    let fix = CadenceValue::Fix64 {
+        value: "-92233720368.54775808".to_string(),
+    };
+    assert_eq!(
+        cadence_value_to_value(&fix).unwrap(),
+        Value::String("-92233720368.54775808".to_string())
+    );
+}
+
+#[test]
+fn short_fixed_point_becomes_number() {
+    assert_eq!(
+        cadence_value_to_value(&ufix64("1.50000000")).unwrap(),
+        json!(1.5)
+    );
+    let back = value_to_cadence_value(&json!(1.5)).unwrap();
+    assert_eq!(
+        back,
+        CadenceValue::Fix64 {
+            value: "1.50000000".to_string()
+        }
+    );
+}
+
+fn with_mapping(number_mapping: NumberMapping) -> ConversionOptions {
+    ConversionOptions {
+        number_mapping,
+        ..ConversionOptions::default()
+    }
+}
+
+#[test]
+fn integers_follow_number_mapping() {
+    assert_eq!(
+        value_to_cadence_value(&json!(-5)).unwrap(),
+        CadenceValue::Int {
+            value: "-5".to_string()
+        }
+    );
+    assert_eq!(
+        value_to_cadence_value(&json!(u64::MAX)).unwrap(),
+        CadenceValue::UInt {
+            value: u64::MAX.to_string()
+        }
+    );
+
+    let options = with_mapping(NumberMapping::UInt8);
+    assert_eq!(
+        value_to_cadence_value_with(&json!([1, 255]), &options).unwrap(),
+        CadenceValue::Array {
+            value: vec![
+                CadenceValue::UInt8 {
+                    value: "1".to_string()
+                },
+                CadenceValue::UInt8 {
+                    value: "255".to_string()
+                },
+            ]
+        }
+    );
+    assert!(value_to_cadence_value_with(&json!(256), &options).is_err());
+    assert!(value_to_cadence_value_with(&json!(-1), &with_mapping(NumberMapping::UInt)).is_err());
+
+    assert_eq!(
+        value_to_cadence_value_with(&json!(100), &with_mapping(NumberMapping::UFix64)).unwrap(),
+        ufix64("100.00000000")
+    );
+}
+
+#[test]
+fn strict_mode_only_recognises_cadence_types() {
+    let input = json!({"type": "premium", "value": 3});
+    assert!(value_to_cadence_value(&input).is_err());
+
+    let strict = ConversionOptions {
+        strict: true,
+        ..ConversionOptions::default()
+    };
+    let CadenceValue::Dictionary { value: entries } =
+        value_to_cadence_value_with(&input, &strict).unwrap()
+    else {
+        panic!("expected a dictionary");
+    };
+    assert_eq!(entries.len(), 2);
+
+    let tagged = json!({"type": "Bool", "value": true});
+    assert_eq!(
+        value_to_cadence_value_with(&tagged, &strict).unwrap(),
+        CadenceValue::Bool { value: true }
+    );
+}
+
+#[test]
+fn deeply_nested_json_errors_instead_of_overflowing() {
+    let mut deep = json!(true);
+    for _ in 0..10_000 {
+        deep = Value::Array(vec![deep]);
+    }
+    assert!(value_to_cadence_value(&deep).is_err());
+
+    let mut deep = CadenceValue::Bool { value: true };
+    for _ in 0..10_000 {
+        deep = CadenceValue::Array { value: vec![deep] };
+    }
+    assert!(cadence_value_to_value(&deep).is_err());
+}
+
+#[test]
+fn optional_contents_are_wrapped_once() {
+    let some_tagged = json!({"type": "Optional", "value": {"type": "Bool", "value": true}});
+    assert_eq!(
+        value_to_cadence_value(&some_tagged).unwrap(),
+        CadenceValue::Optional {
+            value: Some(Box::new(CadenceValue::Bool { value: true }))
+        }
+    );
+
+    let some_plain = json!({"type": "Optional", "value": "x"});
+    assert_eq!(
+        value_to_cadence_value(&some_plain).unwrap(),
+        CadenceValue::Optional {
+            value: Some(Box::new(CadenceValue::String {
+                value: "x".to_string()
+            }))
+        }
+    );
+
+    let none = json!({"type": "Optional", "value": null});
+    assert_eq!(
+        value_to_cadence_value(&none).unwrap(),
+        CadenceValue::Optional { value: None }
+    );
+}
+
+#[test]
+fn characters_parse_and_convert() {
+    let character: CadenceValue =
+        serde_cadence::from_str(r#"{"type":"Character","value":"é"}"#).unwrap();
+    assert_eq!(
+        character,
+        CadenceValue::Character {
+            value: "é".to_string()
+        }
+    );
+    assert_eq!(cadence_value_to_value(&character).unwrap(), json!("é"));
+    assert_eq!(
+        value_to_cadence_value(&json!({"type": "Character", "value": "é"})).unwrap(),
+        character
+    );
+    character
+        .validate_against(&serde_cadence::CadenceType::Character)
+        .unwrap();
+}
+
+#[test]
+fn floats_are_range_checked_as_fix64() {
+    assert_eq!(
+        value_to_cadence_value(&json!(-1.25)).unwrap(),
+        CadenceValue::Fix64 {
+            value: "-1.25000000".to_string()
+        }
+    );
+    assert_eq!(
+        value_to_cadence_value(&json!(92233720368.5)).unwrap(),
+        CadenceValue::Fix64 {
+            value: "92233720368.50000000".to_string()
+        }
+    );
+    assert!(matches!(
+        value_to_cadence_value(&json!(1e12)),
+        Err(serde_cadence::Error::InvalidCadenceValue(_))
+    ));
+    assert!(value_to_cadence_value(&json!(-1e300)).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_options_pin_rust_integers() {
+    let options = EncodeOptions {
+        unsigned: Some(NumberMapping::UInt),
+        ..Default::default()
+    };
+    assert_eq!(
+        to_cadence_value_with(&u64::MAX, &options).unwrap(),
+        CadenceValue::UInt {
+            value: u64::MAX.to_string()
+        }
+    );
+    // Signed integers keep their own types
+    assert_eq!(
+        to_cadence_value_with(&-1i64, &options).unwrap(),
+        CadenceValue::Int64 {
+            value: "-1".to_string()
+        }
+    );
+    // Pinning applies per value, including inside containers
+    let CadenceValue::Array { value: items } =
+        to_cadence_value_with(&vec![1u8, 2], &options).unwrap()
+    else {
+        panic!("expected an array");
+    };
+    assert!(
+        items
+            .iter()
+            .all(|item| matches!(item, CadenceValue::UInt { .. }))
+    );
+    // The options only last for the call
+    assert_eq!(
+        to_cadence_value(&7u64).unwrap(),
+        CadenceValue::UInt64 {
+            value: "7".to_string()
+        }
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_options_leave_built_values_alone() {
+    let options = EncodeOptions {
+        unsigned: Some(NumberMapping::UInt),
+        signed: Some(NumberMapping::Int),
+    };
+    // An enum's rawValue stays a UInt8, while its payload is pinned
+    let encoded = to_cadence_value_with(&Ok::<u64, i64>(5), &options).unwrap();
+    let CadenceValue::Enum { value: composite } = &encoded else {
+        panic!("expected an enum, got {:?}", encoded);
+    };
+    assert_eq!(
+        composite.fields[0].value,
+        CadenceValue::UInt8 {
+            value: "0".to_string()
+        }
+    );
+    assert_eq!(
+        composite.fields[1].value,
+        CadenceValue::UInt {
+            value: "5".to_string()
+        }
+    );
+
+    let bytes = to_cadence_value_with(&Bytes(vec![1, 2]), &options).unwrap();
+    assert_eq!(bytes, Bytes(vec![1, 2]).to_cadence_value().unwrap());
+
+    let built = CadenceValue::UInt8 {
+        value: "1".to_string(),
+    };
+    assert_eq!(to_cadence_value_with(&built, &options).unwrap(), built);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_options_range_check_pinned_integers() {
+    let options = EncodeOptions {
+        unsigned: Some(NumberMapping::UInt8),
+        ..Default::default()
+    };
+    assert!(matches!(
+        to_cadence_value_with(&300u16, &options),
+        Err(Error::InvalidCadenceValue(_))
+    ));
+}
+
+#[test]
+fn numeric_payloads_convert_like_their_string_form() {
+    let numeric = json!({
+        "type": "Struct",
+        "value": {
+            "id": "A.0x1.Vault",
+            "fields": [
+                {"name": "balance", "value": {"type": "UFix64", "value": 1.5}},
+                {"name": "ids", "value": {"type": "Array", "value": [
+                    {"type": "UInt64", "value": 1},
+                    {"type": "Int8", "value": -2}
+                ]}},
+                {"name": "limits", "value": {"type": "Dictionary", "value": [
+                    {"key": {"type": "String", "value": "daily"}, "value": {"type": "UInt", "value": 100}}
+                ]}}
+            ]
+        }
+    });
+    let strings = json!({
+        "type": "Struct",
+        "value": {
+            "id": "A.0x1.Vault",
+            "fields": [
+                {"name": "balance", "value": {"type": "UFix64", "value": "1.5"}},
+                {"name": "ids", "value": {"type": "Array", "value": [
+                    {"type": "UInt64", "value": "1"},
+                    {"type": "Int8", "value": "-2"}
+                ]}},
+                {"name": "limits", "value": {"type": "Dictionary", "value": [
+                    {"key": {"type": "String", "value": "daily"}, "value": {"type": "UInt", "value": "100"}}
+                ]}}
+            ]
+        }
+    });
+    let converted = value_to_cadence_value(&numeric).unwrap();
+    assert_eq!(converted, value_to_cadence_value(&strings).unwrap());
+    assert_eq!(converted, serde_json::from_value(strings).unwrap());
+
+    // Only `value` entries are read as strings
+    let numeric_id = json!({"type": "Struct", "value": {"id": 5, "fields": []}});
+    assert!(value_to_cadence_value(&numeric_id).is_err());
+}
+
+#[test]
+fn json_values_round_trip_through_the_traits() {
+    let original = json!({
+        "name": "vault",
+        "tags": ["a", "b"],
+        "owner": null,
+        "nested": {
+            "count": 3,
+            "negative": -7,
+            "active": true,
+            "empty": {}
+        }
+    });
+    let value = original.to_cadence_value().unwrap();
+    let CadenceValue::Dictionary { value: entries } = &value else {
+        panic!("expected a dictionary, got {:?}", value);
+    };
+    assert_eq!(entries.len(), 4);
+    assert_eq!(
+        value
+            .dictionary_get(&CadenceValue::from("nested"))
+            .and_then(|nested| nested.dictionary_get(&CadenceValue::from("count"))),
+        Some(&CadenceValue::Int {
+            value: "3".to_string()
+        })
+    );
+    assert_eq!(Value::from_cadence_value(&value).unwrap(), original);
+}
@@ -0,0 +1,480 @@
+// tests/derive.rs
+//
+// Behaviour of the ToCadenceValue/FromCadenceValue derive macros
+
+#![cfg(feature = "derive")]
+
+use serde_cadence::{
+    CadenceValue, CompositeField, CompositeValue, Error, FromCadenceValue, ToCadenceValue,
+};
+use std::marker::PhantomData;
+
+fn composite(id: &str, fields: Vec<(&str, CadenceValue)>) -> CompositeValue {
+    CompositeValue {
+        id: id.into(),
+        fields: fields
+            .into_iter()
+            .map(|(name, value)| CompositeField {
+                name: name.into(),
+                value,
+            })
+            .collect(),
+    }
+}
+
+fn int(value: i64) -> CadenceValue {
+    value.to_cadence_value().unwrap()
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+#[cadence(kind = "Event")]
+struct Deposited {
+    amount: i64,
+}
+
+#[test]
+fn structs_decode_from_any_composite_kind() {
+    let fields = composite("Point", vec![("x", int(1)), ("y", int(2))]);
+    for value in [
+        CadenceValue::Struct {
+            value: fields.clone(),
+        },
+        CadenceValue::Resource {
+            value: fields.clone(),
+        },
+        CadenceValue::Event {
+            value: fields.clone(),
+        },
+        CadenceValue::Contract {
+            value: fields.clone(),
+        },
+    ] {
+        assert_eq!(
+            Point::from_cadence_value(&value).unwrap(),
+            Point { x: 1, y: 2 }
+        );
+    }
+    assert!(Point::from_cadence_value(&CadenceValue::Bool { value: true }).is_err());
+}
+
+#[test]
+fn kind_attribute_pins_the_composite_kind() {
+    let event = Deposited { amount: 5 }.to_cadence_value().unwrap();
+    assert_eq!(
+        event,
+        CadenceValue::Event {
+            value: composite("Deposited", vec![("amount", int(5))])
+        }
+    );
+    assert_eq!(
+        Deposited::from_cadence_value(&event).unwrap(),
+        Deposited { amount: 5 }
+    );
+
+    let as_struct = CadenceValue::Struct {
+        value: composite("Deposited", vec![("amount", int(5))]),
+    };
+    assert!(Deposited::from_cadence_value(&as_struct).is_err());
+}
+
+fn default_level() -> u8 {
+    3
+}
+
+#[derive(Debug, PartialEq, FromCadenceValue)]
+struct Settings {
+    name: String,
+    #[cadence(default)]
+    retries: u32,
+    #[cadence(default = "default_level")]
+    level: u8,
+}
+
+#[test]
+fn default_attribute_fills_missing_fields() {
+    let name = "node".to_cadence_value().unwrap();
+    let value = CadenceValue::Struct {
+        value: composite("Settings", vec![("name", name.clone())]),
+    };
+    assert_eq!(
+        Settings::from_cadence_value(&value).unwrap(),
+        Settings {
+            name: "node".to_string(),
+            retries: 0,
+            level: 3,
+        }
+    );
+
+    let value = CadenceValue::Struct {
+        value: composite(
+            "Settings",
+            vec![
+                ("name", name),
+                ("retries", 5u32.to_cadence_value().unwrap()),
+                ("level", 1u8.to_cadence_value().unwrap()),
+            ],
+        ),
+    };
+    assert_eq!(
+        Settings::from_cadence_value(&value).unwrap(),
+        Settings {
+            name: "node".to_string(),
+            retries: 5,
+            level: 1,
+        }
+    );
+
+    let missing_name = CadenceValue::Struct {
+        value: composite("Settings", vec![]),
+    };
+    assert!(Settings::from_cadence_value(&missing_name).is_err());
+}
+
+#[derive(Debug, PartialEq, FromCadenceValue)]
+struct Profile {
+    name: String,
+    nickname: Option<String>,
+}
+
+#[test]
+fn absent_option_fields_decode_as_none() {
+    let name = "alice".to_cadence_value().unwrap();
+    let value = CadenceValue::Struct {
+        value: composite("Profile", vec![("name", name.clone())]),
+    };
+    assert_eq!(
+        Profile::from_cadence_value(&value).unwrap(),
+        Profile {
+            name: "alice".to_string(),
+            nickname: None,
+        }
+    );
+
+    let nickname = Some("al".to_string()).to_cadence_value().unwrap();
+    let value = CadenceValue::Struct {
+        value: composite("Profile", vec![("name", name), ("nickname", nickname)]),
+    };
+    assert_eq!(
+        Profile::from_cadence_value(&value).unwrap().nickname,
+        Some("al".to_string())
+    );
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { width: u32, height: u32 },
+}
+
+#[test]
+fn enums_encode_raw_value_and_payload() {
+    let raw = |index: u8| ("rawValue", index.to_cadence_value().unwrap());
+    let cases = [
+        (Shape::Empty, vec![raw(0)]),
+        (
+            Shape::Circle(4),
+            vec![raw(1), ("0", 4u32.to_cadence_value().unwrap())],
+        ),
+        (
+            Shape::Rect {
+                width: 2,
+                height: 3,
+            },
+            vec![
+                raw(2),
+                ("width", 2u32.to_cadence_value().unwrap()),
+                ("height", 3u32.to_cadence_value().unwrap()),
+            ],
+        ),
+    ];
+    for (shape, fields) in cases {
+        let value = shape.to_cadence_value().unwrap();
+        assert_eq!(
+            value,
+            CadenceValue::Enum {
+                value: composite("Shape", fields)
+            }
+        );
+        assert_eq!(Shape::from_cadence_value(&value).unwrap(), shape);
+    }
+}
+
+#[test]
+fn enums_reject_unknown_raw_values_and_other_kinds() {
+    let unknown = CadenceValue::Enum {
+        value: composite("Shape", vec![("rawValue", 9u8.to_cadence_value().unwrap())]),
+    };
+    assert!(Shape::from_cadence_value(&unknown).is_err());
+
+    let missing_payload = CadenceValue::Enum {
+        value: composite("Shape", vec![("rawValue", 1u8.to_cadence_value().unwrap())]),
+    };
+    assert!(Shape::from_cadence_value(&missing_payload).is_err());
+
+    let as_struct = CadenceValue::Struct {
+        value: composite("Shape", vec![("rawValue", 0u8.to_cadence_value().unwrap())]),
+    };
+    assert!(Shape::from_cadence_value(&as_struct).is_err());
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, ToCadenceValue, FromCadenceValue)]
+struct Labelled {
+    #[serde(skip_serializing_if = "Option::is_none", rename = "displayName")]
+    display_name: Option<String>,
+    #[serde(default, rename(serialize = "ignored"))]
+    count: u8,
+}
+
+#[test]
+fn serde_rename_is_found_among_other_serde_options() {
+    let value = Labelled {
+        display_name: Some("x".to_string()),
+        count: 1,
+    }
+    .to_cadence_value()
+    .unwrap();
+    assert!(value.get("displayName").is_some());
+    assert!(value.get("count").is_some());
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+#[cadence(transparent)]
+struct UserId(u64);
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+#[cadence(transparent)]
+struct Email {
+    address: String,
+}
+
+#[test]
+fn transparent_structs_encode_as_their_field() {
+    let id = UserId(42).to_cadence_value().unwrap();
+    assert_eq!(id, 42u64.to_cadence_value().unwrap());
+    assert_eq!(UserId::from_cadence_value(&id).unwrap(), UserId(42));
+
+    let email = Email {
+        address: "a@b.c".to_string(),
+    };
+    let value = email.to_cadence_value().unwrap();
+    assert_eq!(value, "a@b.c".to_cadence_value().unwrap());
+    assert_eq!(Email::from_cadence_value(&value).unwrap(), email);
+    assert!(Email::from_cadence_value(&id).is_err());
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+struct Wrapper<T> {
+    value: T,
+    items: Vec<T>,
+}
+
+// `Unit` only appears in a PhantomData marker, so it needs no conversions
+#[derive(Debug, PartialEq)]
+struct Flow;
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+struct Amount<Unit> {
+    scaled: u64,
+    unit: PhantomData<Unit>,
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+enum Maybe<T>
+where
+    T: Clone,
+{
+    Nothing,
+    Just(T),
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+#[cadence(transparent)]
+struct Id<T>(T);
+
+#[test]
+fn generic_structs_bound_their_parameters() {
+    let wrapper = Wrapper {
+        value: 1u8,
+        items: vec![2, 3],
+    };
+    let value = wrapper.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::Struct {
+            value: composite(
+                "Wrapper",
+                vec![
+                    ("value", 1u8.to_cadence_value().unwrap()),
+                    ("items", vec![2u8, 3].to_cadence_value().unwrap()),
+                ]
+            ),
+        }
+    );
+    assert_eq!(Wrapper::<u8>::from_cadence_value(&value).unwrap(), wrapper);
+    assert!(Wrapper::<bool>::from_cadence_value(&value).is_err());
+}
+
+#[test]
+fn phantom_parameters_need_no_conversions() {
+    let amount: Amount<Flow> = Amount {
+        scaled: 5,
+        unit: PhantomData,
+    };
+    let value = amount.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::Struct {
+            value: composite("Amount", vec![("scaled", 5u64.to_cadence_value().unwrap())]),
+        }
+    );
+    assert_eq!(Amount::<Flow>::from_cadence_value(&value).unwrap(), amount);
+}
+
+#[test]
+fn generic_enums_and_transparent_wrappers() {
+    let just = Maybe::Just("x".to_string()).to_cadence_value().unwrap();
+    assert_eq!(
+        Maybe::<String>::from_cadence_value(&just).unwrap(),
+        Maybe::Just("x".to_string())
+    );
+    let nothing = Maybe::<u8>::Nothing.to_cadence_value().unwrap();
+    assert_eq!(
+        Maybe::<u8>::from_cadence_value(&nothing).unwrap(),
+        Maybe::Nothing
+    );
+
+    let id = Id(7u32).to_cadence_value().unwrap();
+    assert_eq!(id, 7u32.to_cadence_value().unwrap());
+    assert_eq!(Id::<u32>::from_cadence_value(&id).unwrap(), Id(7));
+}
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+struct Marker;
+
+#[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+#[cadence(kind = "Event")]
+struct Ping {}
+
+#[test]
+fn unit_and_empty_structs_carry_no_fields() {
+    let marker = Marker.to_cadence_value().unwrap();
+    assert_eq!(
+        marker,
+        CadenceValue::Struct {
+            value: composite("Marker", vec![]),
+        }
+    );
+    assert_eq!(Marker::from_cadence_value(&marker).unwrap(), Marker);
+    assert_eq!(
+        Marker::from_cadence_value(&CadenceValue::Void {}).unwrap(),
+        Marker
+    );
+
+    let ping = Ping {}.to_cadence_value().unwrap();
+    assert!(ping.is_event());
+    assert_eq!(Ping::from_cadence_value(&ping).unwrap(), Ping {});
+    assert_eq!(
+        Ping::from_cadence_value(&CadenceValue::Void {}).unwrap(),
+        Ping {}
+    );
+    assert!(Ping::from_cadence_value(&marker).is_err());
+    assert!(Marker::from_cadence_value(&CadenceValue::Bool { value: true }).is_err());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ToCadenceValue, FromCadenceValue)]
+#[cadence(repr = "string")]
+enum Status {
+    Listed,
+    Sold,
+    Delisted,
+}
+
+#[test]
+fn string_repr_enums_round_trip_as_case_names() {
+    for (status, name) in [
+        (Status::Listed, "Listed"),
+        (Status::Sold, "Sold"),
+        (Status::Delisted, "Delisted"),
+    ] {
+        let value = status.to_cadence_value().unwrap();
+        assert_eq!(
+            value,
+            CadenceValue::String {
+                value: name.to_string()
+            }
+        );
+        assert_eq!(Status::from_cadence_value(&value).unwrap(), status);
+    }
+}
+
+#[test]
+fn string_repr_enums_reject_unknown_cases() {
+    let unknown = CadenceValue::String {
+        value: "Burned".to_string(),
+    };
+    match Status::from_cadence_value(&unknown) {
+        Err(Error::InvalidCadenceValue(message)) => {
+            assert!(message.contains("\"Burned\"") && message.contains("Status"))
+        }
+        other => panic!("expected an unknown-case error, got {:?}", other),
+    }
+    // Case names are matched exactly
+    let lowercase = CadenceValue::String {
+        value: "sold".to_string(),
+    };
+    assert!(Status::from_cadence_value(&lowercase).is_err());
+    assert!(matches!(
+        Status::from_cadence_value(&int(1)),
+        Err(Error::TypeMismatch { .. })
+    ));
+}
+
+#[derive(
+    Debug, PartialEq, serde::Serialize, serde::Deserialize, ToCadenceValue, FromCadenceValue,
+)]
+struct Badge {
+    label: String,
+    rarity: char,
+}
+
+#[test]
+fn char_fields_round_trip_as_characters() {
+    let badge = Badge {
+        label: "gold".to_string(),
+        rarity: 'é',
+    };
+    let json = serde_cadence::to_string(&badge).unwrap();
+    assert!(
+        json.contains(r#"{"type":"Character","value":"é"}"#),
+        "{}",
+        json
+    );
+    assert_eq!(serde_cadence::from_str::<Badge>(&json).unwrap(), badge);
+}
+
+#[test]
+fn multi_char_characters_do_not_fit_a_char() {
+    // A flag is one grapheme cluster made of two code points
+    let json = r#"{"type":"Struct","value":{"id":"Badge","fields":[
+        {"name":"label","value":{"type":"String","value":"gold"}},
+        {"name":"rarity","value":{"type":"Character","value":"🇺🇦"}}
+    ]}}"#;
+    match serde_cadence::from_str::<Badge>(json) {
+        Err(Error::AtPath { path, source }) => {
+            assert_eq!(path, "rarity");
+            assert!(
+                matches!(*source, Error::InvalidCadenceValue(_)),
+                "{:?}",
+                source
+            );
+        }
+        other => panic!("expected an error at rarity, got {:?}", other),
+    }
+}
@@ -0,0 +1,37 @@
+// tests/error.rs
+//
+// Error paths reported for nested conversion failures
+
+use serde_cadence::{CadenceValue, Error, FromCadenceValue, ToCadenceValue};
+use std::collections::BTreeMap;
+
+#[test]
+fn nested_failures_report_their_path() {
+    let value = vec![vec![1u16], vec![2, 300]].to_cadence_value().unwrap();
+    let error = Vec::<Vec<u8>>::from_cadence_value(&value).unwrap_err();
+    let Error::AtPath { path, source } = &error else {
+        panic!("expected an AtPath error, got {:?}", error);
+    };
+    assert_eq!(path, "[1][1]");
+    assert!(!matches!(**source, Error::AtPath { .. }));
+    assert!(error.to_string().starts_with("at [1][1]: "));
+}
+
+#[test]
+fn paths_join_fields_and_indices() {
+    let error = Error::Custom("bad".to_string())
+        .at_index(2)
+        .at_field("items")
+        .at_field("order");
+    assert_eq!(error.to_string(), "at order.items[2]: bad");
+}
+
+#[test]
+fn map_values_report_their_position() {
+    let mut map = BTreeMap::new();
+    map.insert("a".to_string(), CadenceValue::Bool { value: true });
+    map.insert("b".to_string(), "x".to_cadence_value().unwrap());
+    let value = map.to_cadence_value().unwrap();
+    let error = BTreeMap::<String, bool>::from_cadence_value(&value).unwrap_err();
+    assert!(matches!(error, Error::AtPath { .. }), "{:?}", error);
+}
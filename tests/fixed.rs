@@ -0,0 +1,120 @@
+// tests/fixed.rs
+//
+// Exact UFix64 and Fix64 decimals: parsing, arithmetic, conversion and serde
+
+use serde_cadence::{CadenceValue, Fix64, FromCadenceValue, ToCadenceValue, UFix64};
+
+#[test]
+fn ufix64_parses_and_displays_exactly() {
+    let amount: UFix64 = "1.5".parse().unwrap();
+    assert_eq!(amount.scaled(), 150_000_000);
+    assert_eq!(amount.to_string(), "1.50000000");
+    assert_eq!(UFix64::MAX.to_string(), "184467440737.09551615");
+    assert_eq!(
+        "184467440737.09551615".parse::<UFix64>().unwrap(),
+        UFix64::MAX
+    );
+    assert!("184467440737.09551616".parse::<UFix64>().is_err());
+    assert!("-1.0".parse::<UFix64>().is_err());
+    assert!("abc".parse::<UFix64>().is_err());
+}
+
+#[test]
+fn ufix64_arithmetic_is_checked() {
+    let one = UFix64::from_scaled(100_000_000);
+    let half: UFix64 = "0.5".parse().unwrap();
+    assert_eq!(one.checked_add(half).unwrap().to_string(), "1.50000000");
+    assert_eq!(one.checked_sub(half).unwrap(), half);
+    assert_eq!(half.checked_sub(one), None);
+    assert_eq!(UFix64::MAX.checked_add(half), None);
+    assert_eq!(half.checked_mul_u64(4).unwrap(), "2.0".parse().unwrap());
+}
+
+#[test]
+fn ufix64_converts_to_and_from_cadence_values() {
+    let amount: UFix64 = "0.00000001".parse().unwrap();
+    let value = amount.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::UFix64 {
+            value: "0.00000001".to_string()
+        }
+    );
+    assert_eq!(UFix64::from_cadence_value(&value).unwrap(), amount);
+
+    let positive = CadenceValue::Fix64 {
+        value: "2.5".to_string(),
+    };
+    assert_eq!(
+        UFix64::from_cadence_value(&positive).unwrap(),
+        "2.5".parse().unwrap()
+    );
+    let negative = CadenceValue::Fix64 {
+        value: "-2.5".to_string(),
+    };
+    assert!(UFix64::from_cadence_value(&negative).is_err());
+}
+
+#[test]
+fn ufix64_serializes_as_a_decimal_string() {
+    let amount: UFix64 = "12.34".parse().unwrap();
+    let json = serde_json::to_string(&amount).unwrap();
+    assert_eq!(json, r#""12.34000000""#);
+    assert_eq!(serde_json::from_str::<UFix64>(&json).unwrap(), amount);
+    assert_eq!(
+        serde_json::from_str::<UFix64>(r#""7""#).unwrap().scaled(),
+        700_000_000
+    );
+    assert!(serde_json::from_str::<UFix64>("1234000000").is_err());
+    assert!(serde_json::from_str::<UFix64>(r#""-1.0""#).is_err());
+}
+
+#[test]
+fn fix64_handles_negative_values() {
+    let debt: Fix64 = "-3.25".parse().unwrap();
+    assert_eq!(debt.scaled(), -325_000_000);
+    assert_eq!(debt.to_string(), "-3.25000000");
+    assert_eq!(Fix64::MIN.to_string(), "-92233720368.54775808");
+    assert_eq!(Fix64::MAX.to_string(), "92233720368.54775807");
+    assert!("92233720368.54775808".parse::<Fix64>().is_err());
+
+    let three: Fix64 = "3.25".parse().unwrap();
+    assert_eq!(debt.checked_add(three).unwrap(), Fix64::ZERO);
+    assert_eq!(debt.checked_mul_i64(-2).unwrap(), "6.5".parse().unwrap());
+    assert_eq!(Fix64::MIN.checked_sub(three), None);
+}
+
+#[test]
+fn fix64_converts_to_and_from_cadence_values() {
+    let debt: Fix64 = "-0.5".parse().unwrap();
+    let value = debt.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::Fix64 {
+            value: "-0.50000000".to_string()
+        }
+    );
+    assert_eq!(Fix64::from_cadence_value(&value).unwrap(), debt);
+
+    let small = CadenceValue::UFix64 {
+        value: "1.0".to_string(),
+    };
+    assert_eq!(
+        Fix64::from_cadence_value(&small).unwrap().scaled(),
+        100_000_000
+    );
+    let too_large = CadenceValue::UFix64 {
+        value: "184467440737.09551615".to_string(),
+    };
+    assert!(Fix64::from_cadence_value(&too_large).is_err());
+    assert!(Fix64::from_cadence_value(&CadenceValue::Bool { value: true }).is_err());
+}
+
+#[test]
+fn fix64_serializes_as_a_decimal_string() {
+    let debt: Fix64 = "-12.5".parse().unwrap();
+    let json = serde_json::to_string(&debt).unwrap();
+    assert_eq!(json, r#""-12.50000000""#);
+    assert_eq!(serde_json::from_str::<Fix64>(&json).unwrap(), debt);
+    assert!(serde_json::from_str::<Fix64>("-1250000000").is_err());
+}
@@ -0,0 +1,196 @@
+// tests/flow.rs
+//
+// Decoding the built-in Flow composites returned by scripts
+
+use serde_cadence::{
+    Account, AccountKey, Block, CadenceValue, Error, FromCadenceValue, HashAlgorithm,
+    SignatureAlgorithm, from_str_value,
+};
+
+fn account_json(extra_fields: &str) -> String {
+    format!(
+        r#"{{"type":"Struct","value":{{"id":"Account","fields":[
+            {{"name":"address","value":{{"type":"Address","value":"0xf8d6e0586b0a20c7"}}}},
+            {{"name":"balance","value":{{"type":"UFix64","value":"10.50000000"}}}}{}
+        ]}}}}"#,
+        extra_fields
+    )
+}
+
+#[test]
+fn accounts_decode_their_standard_fields() {
+    let value = from_str_value(&account_json(
+        r#",{"name":"storageUsed","value":{"type":"UInt64","value":"1024"}},
+            {"name":"storageCapacity","value":{"type":"UInt64","value":"100000"}},
+            {"name":"availableBalance","value":{"type":"UFix64","value":"10.4"}},
+            {"name":"contracts","value":{"type":"Array","value":[]}}"#,
+    ))
+    .unwrap();
+    let account = Account::from_cadence_value(&value).unwrap();
+    assert_eq!(account.address.to_string(), "0xf8d6e0586b0a20c7");
+    assert_eq!(account.balance, "10.5".parse().unwrap());
+    assert_eq!(account.available_balance, Some("10.4".parse().unwrap()));
+    assert_eq!(account.storage_used, Some(1024));
+    assert_eq!(account.storage_capacity, Some(100000));
+}
+
+#[test]
+fn accounts_tolerate_missing_optional_fields() {
+    let value = from_str_value(&account_json("")).unwrap();
+    let account = Account::from_cadence_value(&value).unwrap();
+    assert_eq!(account.available_balance, None);
+    assert_eq!(account.storage_used, None);
+    assert_eq!(account.storage_capacity, None);
+}
+
+#[test]
+fn accounts_report_bad_fields() {
+    let value = from_str_value(
+        r#"{"type":"Struct","value":{"id":"Account","fields":[
+            {"name":"address","value":{"type":"Address","value":"0x1"}}
+        ]}}"#,
+    )
+    .unwrap();
+    let error = Account::from_cadence_value(&value).unwrap_err();
+    assert!(error.to_string().contains("balance"), "{}", error);
+
+    let value = from_str_value(&account_json(
+        r#",{"name":"storageUsed","value":{"type":"String","value":"lots"}}"#,
+    ))
+    .unwrap();
+    let error = Account::from_cadence_value(&value).unwrap_err();
+    assert!(
+        error.to_string().starts_with("at storageUsed: "),
+        "{}",
+        error
+    );
+
+    assert!(Account::from_cadence_value(&CadenceValue::Bool { value: true }).is_err());
+}
+
+#[test]
+fn blocks_decode_from_any_composite_kind() {
+    let id: Vec<String> = (0..32)
+        .map(|i| format!(r#"{{"type":"UInt8","value":"{}"}}"#, i))
+        .collect();
+    let value = from_str_value(&format!(
+        r#"{{"type":"Resource","value":{{"id":"Block","fields":[
+            {{"name":"id","value":{{"type":"Array","value":[{}]}}}},
+            {{"name":"height","value":{{"type":"UInt64","value":"42"}}}},
+            {{"name":"view","value":{{"type":"UInt64","value":"43"}}}},
+            {{"name":"timestamp","value":{{"type":"UFix64","value":"1700000000.0"}}}}
+        ]}}}}"#,
+        id.join(",")
+    ))
+    .unwrap();
+    let block = Block::from_cadence_value(&value).unwrap();
+    assert_eq!(block.id.0, (0..32).collect::<Vec<u8>>());
+    assert_eq!(block.height, 42);
+    assert_eq!(block.view, 43);
+    assert_eq!(block.timestamp.scaled(), 170_000_000_000_000_000);
+}
+
+const ACCOUNT_KEY_JSON: &str = r#"{
+    "type": "Struct",
+    "value": {
+        "id": "AccountKey",
+        "fields": [
+            {"name": "keyIndex", "value": {"type": "Int", "value": "2"}},
+            {"name": "publicKey", "value": {"type": "Struct", "value": {
+                "id": "PublicKey",
+                "fields": [
+                    {"name": "publicKey", "value": {"type": "Array", "value": [
+                        {"type": "UInt8", "value": "4"},
+                        {"type": "UInt8", "value": "183"},
+                        {"type": "UInt8", "value": "42"}
+                    ]}},
+                    {"name": "signatureAlgorithm", "value": {"type": "Enum", "value": {
+                        "id": "SignatureAlgorithm",
+                        "fields": [{"name": "rawValue", "value": {"type": "UInt8", "value": "2"}}]
+                    }}}
+                ]
+            }}},
+            {"name": "hashAlgorithm", "value": {"type": "Enum", "value": {
+                "id": "HashAlgorithm",
+                "fields": [{"name": "rawValue", "value": {"type": "UInt8", "value": "3"}}]
+            }}},
+            {"name": "weight", "value": {"type": "UFix64", "value": "1000.00000000"}},
+            {"name": "isRevoked", "value": {"type": "Bool", "value": true}}
+        ]
+    }
+}"#;
+
+#[test]
+fn account_keys_decode_from_script_results() {
+    let value = from_str_value(ACCOUNT_KEY_JSON).unwrap();
+    let key = AccountKey::from_cadence_value(&value).unwrap();
+    assert_eq!(key.key_index, 2);
+    assert_eq!(&key.public_key.public_key[..], &[4, 183, 42]);
+    assert_eq!(
+        key.public_key.signature_algorithm,
+        SignatureAlgorithm::EcdsaSecp256k1
+    );
+    assert_eq!(key.hash_algorithm, HashAlgorithm::Sha3_256);
+    assert_eq!(key.weight, "1000.0".parse().unwrap());
+    assert!(key.is_revoked);
+
+    let without_algorithm =
+        ACCOUNT_KEY_JSON.replace(r#""name": "hashAlgorithm""#, r#""name": "hash""#);
+    let value = from_str_value(&without_algorithm).unwrap();
+    assert!(AccountKey::from_cadence_value(&value).is_err());
+}
+
+#[test]
+fn builtin_enums_map_their_raw_values() {
+    let signature_algorithms = [
+        SignatureAlgorithm::EcdsaP256,
+        SignatureAlgorithm::EcdsaSecp256k1,
+        SignatureAlgorithm::BlsBls12381,
+    ];
+    for (raw_value, algorithm) in (1u8..).zip(signature_algorithms) {
+        let value = CadenceValue::enum_case("SignatureAlgorithm", raw_value);
+        assert_eq!(
+            SignatureAlgorithm::from_cadence_value(&value).unwrap(),
+            algorithm
+        );
+    }
+
+    let hash_algorithms = [
+        HashAlgorithm::Sha2_256,
+        HashAlgorithm::Sha2_384,
+        HashAlgorithm::Sha3_256,
+        HashAlgorithm::Sha3_384,
+        HashAlgorithm::Kmac128BlsBls12381,
+        HashAlgorithm::Keccak256,
+    ];
+    for (raw_value, algorithm) in (1u8..).zip(hash_algorithms) {
+        let value = CadenceValue::enum_case("HashAlgorithm", raw_value);
+        assert_eq!(
+            HashAlgorithm::from_cadence_value(&value).unwrap(),
+            algorithm
+        );
+    }
+}
+
+#[test]
+fn builtin_enums_reject_unknown_cases() {
+    for raw_value in [0u8, 4] {
+        let value = CadenceValue::enum_case("SignatureAlgorithm", raw_value);
+        match SignatureAlgorithm::from_cadence_value(&value) {
+            Err(Error::InvalidCadenceValue(message)) => assert_eq!(
+                message,
+                format!("Unknown rawValue {} for enum SignatureAlgorithm", raw_value)
+            ),
+            other => panic!("expected an unknown-case error, got {:?}", other),
+        }
+    }
+    let value = CadenceValue::enum_case("HashAlgorithm", 7u8);
+    assert!(matches!(
+        HashAlgorithm::from_cadence_value(&value),
+        Err(Error::InvalidCadenceValue(_))
+    ));
+    assert!(matches!(
+        HashAlgorithm::from_cadence_value(&CadenceValue::Bool { value: true }),
+        Err(Error::TypeMismatch { .. })
+    ));
+}
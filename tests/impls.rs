@@ -0,0 +1,449 @@
+// tests/impls.rs
+//
+// Behaviour of the ToCadenceValue/FromCadenceValue impls for standard types
+
+use serde_cadence::{CadenceValue, FromCadenceValue, Pairs, ToCadenceValue};
+use std::num::{NonZeroI16, NonZeroU32};
+use std::time::Duration;
+
+#[test]
+fn nested_options_keep_their_shape() {
+    let none: Option<Option<u8>> = None;
+    let some_none: Option<Option<u8>> = Some(None);
+
+    let none_value = none.to_cadence_value().unwrap();
+    let some_none_value = some_none.to_cadence_value().unwrap();
+    assert_eq!(
+        serde_json::to_string(&none_value).unwrap(),
+        r#"{"type":"Optional","value":null}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&some_none_value).unwrap(),
+        r#"{"type":"Optional","value":{"type":"Optional","value":null}}"#
+    );
+
+    assert_eq!(
+        Option::<Option<u8>>::from_cadence_value(&none_value).unwrap(),
+        None
+    );
+    assert_eq!(
+        Option::<Option<u8>>::from_cadence_value(&some_none_value).unwrap(),
+        Some(None)
+    );
+    let some_some = Some(Some(7u8)).to_cadence_value().unwrap();
+    assert_eq!(
+        Option::<Option<u8>>::from_cadence_value(&some_some).unwrap(),
+        Some(Some(7))
+    );
+}
+
+#[test]
+fn option_rejects_non_optional_values() {
+    let value = CadenceValue::from(true);
+    assert!(Option::<bool>::from_cadence_value(&value).is_err());
+}
+
+#[test]
+fn unit_maps_to_void() {
+    let value = ().to_cadence_value().unwrap();
+    assert_eq!(value, CadenceValue::Void {});
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"type":"Void"}"#);
+    <()>::from_cadence_value(&value).unwrap();
+    assert!(<()>::from_cadence_value(&CadenceValue::from(false)).is_err());
+}
+
+#[test]
+fn smart_pointers_are_transparent() {
+    use std::sync::Arc;
+
+    let boxed = Box::new(5u16);
+    let shared = Arc::new(5u16);
+    let expected = 5u16.to_cadence_value().unwrap();
+    assert_eq!(boxed.to_cadence_value().unwrap(), expected);
+    assert_eq!(shared.to_cadence_value().unwrap(), expected);
+    assert_eq!((&&5u16).to_cadence_value().unwrap(), expected);
+
+    assert_eq!(*Box::<u16>::from_cadence_value(&expected).unwrap(), 5);
+    assert_eq!(*Arc::<u16>::from_cadence_value(&expected).unwrap(), 5);
+}
+
+#[test]
+fn cow_str_round_trips_as_string() {
+    use std::borrow::Cow;
+
+    let borrowed: Cow<'_, str> = Cow::Borrowed("flow");
+    let value = borrowed.to_cadence_value().unwrap();
+    assert_eq!(value, CadenceValue::from("flow".to_string()));
+    let decoded = Cow::<'static, str>::from_cadence_value(&value).unwrap();
+    assert_eq!(decoded, "flow");
+}
+
+#[test]
+fn non_zero_integers_reject_zero() {
+    let seven = NonZeroU32::new(7).unwrap();
+    let value = seven.to_cadence_value().unwrap();
+    assert_eq!(value, 7u32.to_cadence_value().unwrap());
+    assert_eq!(NonZeroU32::from_cadence_value(&value).unwrap(), seven);
+
+    let zero = 0i16.to_cadence_value().unwrap();
+    assert!(NonZeroI16::from_cadence_value(&zero).is_err());
+    let minus_one = NonZeroI16::new(-1).unwrap();
+    assert_eq!(
+        NonZeroI16::from_cadence_value(&minus_one.to_cadence_value().unwrap()).unwrap(),
+        minus_one
+    );
+}
+
+#[test]
+fn try_from_matches_from_cadence_value() {
+    let value = CadenceValue::UInt16 {
+        value: "513".to_string(),
+    };
+    assert_eq!(u16::try_from(value.clone()).unwrap(), 513);
+    assert!(u8::try_from(value.clone()).is_err());
+    assert!(String::try_from(value).is_err());
+    assert!(bool::try_from(CadenceValue::Bool { value: true }).unwrap());
+}
+
+#[test]
+fn from_primitives_matches_to_cadence_value() {
+    assert_eq!(CadenceValue::from(true), true.to_cadence_value().unwrap());
+    assert_eq!(CadenceValue::from("hi"), "hi".to_cadence_value().unwrap());
+    assert_eq!(
+        CadenceValue::from("hi".to_string()),
+        CadenceValue::String {
+            value: "hi".to_string()
+        }
+    );
+    assert_eq!(CadenceValue::from(-3i8), (-3i8).to_cadence_value().unwrap());
+    assert_eq!(
+        CadenceValue::from(u64::MAX),
+        CadenceValue::UInt64 {
+            value: "18446744073709551615".to_string()
+        }
+    );
+}
+
+#[test]
+fn to_cadence_dictionary_keeps_entry_order() {
+    let value = serde_cadence::to_cadence_dictionary([("b", 2u8), ("a", 1u8)]).unwrap();
+    let CadenceValue::Dictionary { value: entries } = &value else {
+        panic!("expected a dictionary, got {:?}", value);
+    };
+    let keys: Vec<_> = entries
+        .iter()
+        .map(|entry| String::from_cadence_value(&entry.key).unwrap())
+        .collect();
+    assert_eq!(keys, ["b", "a"]);
+    assert_eq!(entries[0].value, 2u8.to_cadence_value().unwrap());
+
+    let empty = serde_cadence::to_cadence_dictionary(Vec::<(String, u8)>::new()).unwrap();
+    assert_eq!(empty, CadenceValue::Dictionary { value: vec![] });
+}
+
+#[test]
+fn tuples_round_trip_as_arrays() {
+    let pair = ("x".to_string(), 7u8);
+    let value = pair.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::Array {
+            value: vec![
+                "x".to_cadence_value().unwrap(),
+                7u8.to_cadence_value().unwrap()
+            ]
+        }
+    );
+    assert_eq!(<(String, u8)>::from_cadence_value(&value).unwrap(), pair);
+
+    let six = (1u8, 2u8, 3u8, 4u8, 5u8, true);
+    let value = six.to_cadence_value().unwrap();
+    assert_eq!(
+        <(u8, u8, u8, u8, u8, bool)>::from_cadence_value(&value).unwrap(),
+        six
+    );
+}
+
+#[test]
+fn tuples_reject_the_wrong_length() {
+    let three = vec![1u8, 2, 3].to_cadence_value().unwrap();
+    match <(u8, u8)>::from_cadence_value(&three) {
+        Err(serde_cadence::Error::Custom(message)) => {
+            assert_eq!(message, "Expected array of length 2 for 2-tuple, got 3")
+        }
+        other => panic!("expected a length error, got {:?}", other),
+    }
+    assert!(<(u8, u8, u8, u8)>::from_cadence_value(&three).is_err());
+}
+
+#[test]
+fn integers_decode_from_same_signedness_variants() {
+    let word = CadenceValue::Word16 {
+        value: "200".to_string(),
+    };
+    assert_eq!(u8::from_cadence_value(&word).unwrap(), 200);
+    assert_eq!(u64::from_cadence_value(&word).unwrap(), 200);
+    assert!(matches!(
+        i32::from_cadence_value(&word),
+        Err(serde_cadence::Error::TypeMismatch { .. })
+    ));
+
+    let wide = CadenceValue::Int128 {
+        value: "-70000".to_string(),
+    };
+    assert_eq!(i32::from_cadence_value(&wide).unwrap(), -70000);
+    assert!(i16::from_cadence_value(&wide).is_err());
+
+    let int = CadenceValue::Int {
+        value: "5".to_string(),
+    };
+    assert_eq!(u8::from_cadence_value(&int).unwrap(), 5);
+}
+
+#[test]
+fn integer_errors_tell_range_from_bad_digits() {
+    let too_big = CadenceValue::UInt16 {
+        value: "300".to_string(),
+    };
+    match u8::from_cadence_value(&too_big) {
+        Err(serde_cadence::Error::InvalidCadenceValue(message)) => {
+            assert_eq!(message, "value 300 out of range for UInt8")
+        }
+        other => panic!("expected a range error, got {:?}", other),
+    }
+    let too_small = CadenceValue::Int {
+        value: "-129".to_string(),
+    };
+    let error = i8::from_cadence_value(&too_small).unwrap_err();
+    assert!(
+        error.to_string().contains("out of range for Int8"),
+        "{}",
+        error
+    );
+
+    let garbage = CadenceValue::UInt8 {
+        value: "12a".to_string(),
+    };
+    match u8::from_cadence_value(&garbage) {
+        Err(serde_cadence::Error::InvalidCadenceValue(message)) => {
+            assert_eq!(message, r#""12a" is not a valid integer"#)
+        }
+        other => panic!("expected a digit error, got {:?}", other),
+    }
+}
+
+#[test]
+fn durations_are_ufix64_seconds() {
+    let duration = Duration::new(90, 500_000_019);
+    let value = duration.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::UFix64 {
+            value: "90.50000001".to_string()
+        }
+    );
+    // Nanoseconds below the 8th decimal are truncated
+    assert_eq!(
+        Duration::from_cadence_value(&value).unwrap(),
+        Duration::new(90, 500_000_010)
+    );
+    assert_eq!(
+        Duration::ZERO.to_cadence_value().unwrap(),
+        CadenceValue::UFix64 {
+            value: "0.00000000".to_string()
+        }
+    );
+    assert!(Duration::MAX.to_cadence_value().is_err());
+
+    let negative = CadenceValue::Fix64 {
+        value: "-1.0".to_string(),
+    };
+    assert!(Duration::from_cadence_value(&negative).is_err());
+}
+
+#[test]
+fn results_encode_as_enums() {
+    let ok: Result<u8, String> = Ok(3);
+    let value = ok.to_cadence_value().unwrap();
+    assert_eq!(value.enum_type_id(), Some("Result"));
+    assert_eq!(
+        value.enum_raw_value(),
+        Some(&0u8.to_cadence_value().unwrap())
+    );
+    assert_eq!(value.get("0"), Some(&3u8.to_cadence_value().unwrap()));
+    assert_eq!(
+        Result::<u8, String>::from_cadence_value(&value).unwrap(),
+        ok
+    );
+
+    let err: Result<u8, String> = Err("nope".to_string());
+    let value = err.to_cadence_value().unwrap();
+    assert_eq!(
+        value.enum_raw_value(),
+        Some(&1u8.to_cadence_value().unwrap())
+    );
+    assert_eq!(
+        Result::<u8, String>::from_cadence_value(&value).unwrap(),
+        err
+    );
+
+    // The payload is decoded as the type of the variant it belongs to
+    assert!(Result::<String, u8>::from_cadence_value(&value).is_err());
+    assert!(Result::<u8, String>::from_cadence_value(&3u8.to_cadence_value().unwrap()).is_err());
+    let unknown = CadenceValue::enum_case("Result", 2u8);
+    assert!(Result::<u8, u8>::from_cadence_value(&unknown).is_err());
+}
+
+// Takes the value by type, so references go through their own impls
+fn encode<T: ToCadenceValue>(value: T) -> CadenceValue {
+    value.to_cadence_value().unwrap()
+}
+
+#[test]
+fn references_encode_like_their_target() {
+    let number = 9u32;
+    let expected = number.to_cadence_value().unwrap();
+    let by_ref: &u32 = &number;
+    let by_double_ref: &&u32 = &by_ref;
+    assert_eq!(encode(by_ref), expected);
+    assert_eq!(encode(by_double_ref), expected);
+
+    let mut text = String::from("hi");
+    let expected = text.to_cadence_value().unwrap();
+    assert_eq!(encode(&mut text), expected);
+    assert_eq!(encode("hi"), expected);
+
+    let items: Vec<&str> = vec!["a", "b"];
+    assert_eq!(
+        items.to_cadence_value().unwrap(),
+        vec!["a".to_string(), "b".to_string()]
+            .to_cadence_value()
+            .unwrap()
+    );
+}
+
+#[test]
+fn try_from_works_by_reference() {
+    let value = CadenceValue::UInt16 {
+        value: "513".to_string(),
+    };
+    assert_eq!(u16::try_from(&value).unwrap(), 513);
+    assert!(u8::try_from(&value).is_err());
+    assert!(String::try_from(&value).is_err());
+
+    let path = CadenceValue::Path {
+        value: serde_cadence::PathValue {
+            domain: serde_cadence::PathDomain::Storage,
+            identifier: "vault".to_string(),
+        },
+    };
+    let decoded = serde_cadence::PathValue::try_from(&path).unwrap();
+    assert_eq!(decoded.identifier, "vault");
+    assert_eq!(decoded.to_cadence_value().unwrap(), path);
+    assert!(serde_cadence::CapabilityValue::try_from(&path).is_err());
+    assert!(serde_cadence::Block::try_from(&path).is_err());
+
+    let capability = CadenceValue::Capability {
+        value: serde_cadence::CapabilityValue {
+            id: "3".to_string(),
+            address: "0x1".to_string(),
+            borrow_type: serde_cadence::CadenceType::Int,
+        },
+    };
+    let decoded = serde_cadence::CapabilityValue::try_from(&capability).unwrap();
+    assert_eq!(decoded.to_cadence_value().unwrap(), capability);
+}
+
+#[test]
+fn pairs_keep_order_and_duplicate_keys() {
+    let pairs = Pairs(vec![
+        ("zebra".to_string(), 1u8),
+        ("apple".to_string(), 2),
+        ("zebra".to_string(), 3),
+    ]);
+    let value = pairs.to_cadence_value().unwrap();
+    let CadenceValue::Dictionary { value: entries } = &value else {
+        panic!("expected a dictionary, got {:?}", value);
+    };
+    let keys: Vec<_> = entries
+        .iter()
+        .map(|entry| String::from_cadence_value(&entry.key).unwrap())
+        .collect();
+    assert_eq!(keys, ["zebra", "apple", "zebra"]);
+
+    let decoded = Pairs::<String, u8>::from_cadence_value(&value).unwrap();
+    assert_eq!(decoded, pairs);
+    assert!(
+        Pairs::<String, u8>::from_cadence_value(&CadenceValue::Array { value: vec![] }).is_err()
+    );
+}
+
+#[test]
+fn mixed_arrays_decode_as_cadence_values() {
+    let mixed: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Array","value":[
+            {"type":"String","value":"Alice"},
+            {"type":"Int","value":"30"},
+            {"type":"Bool","value":true}
+        ]}"#,
+    )
+    .unwrap();
+    let items = Vec::<CadenceValue>::from_cadence_value(&mixed).unwrap();
+    assert_eq!(
+        items,
+        [
+            CadenceValue::from("Alice"),
+            CadenceValue::Int {
+                value: "30".to_string()
+            },
+            CadenceValue::from(true),
+        ]
+    );
+    assert_eq!(items.to_cadence_value().unwrap(), mixed);
+    // A typed Vec still needs every item to match
+    assert!(Vec::<String>::from_cadence_value(&mixed).is_err());
+}
+
+#[test]
+fn boxed_cadence_values_are_transparent() {
+    let value = CadenceValue::Optional {
+        value: Some(Box::new(CadenceValue::from("inner"))),
+    };
+    let boxed = Box::new(value.clone());
+    assert_eq!(boxed.to_cadence_value().unwrap(), value);
+    assert_eq!(
+        *Box::<CadenceValue>::from_cadence_value(&value).unwrap(),
+        value
+    );
+
+    // Generic code boxing its values works for CadenceValue too
+    fn rebox<T: ToCadenceValue + FromCadenceValue>(value: &CadenceValue) -> CadenceValue {
+        let boxed = Box::<T>::from_cadence_value(value).unwrap();
+        boxed.to_cadence_value().unwrap()
+    }
+    assert_eq!(rebox::<CadenceValue>(&value), value);
+    assert_eq!(rebox::<Option<String>>(&value), value);
+}
+
+#[test]
+fn unsigned_integers_read_word_types() {
+    let nonce = CadenceValue::Word64 {
+        value: u64::MAX.to_string(),
+    };
+    assert_eq!(u64::from_cadence_value(&nonce).unwrap(), u64::MAX);
+    assert!(u32::from_cadence_value(&nonce).is_err());
+
+    for signed in [
+        CadenceValue::Int64 {
+            value: "5".to_string(),
+        },
+        CadenceValue::Int8 {
+            value: "5".to_string(),
+        },
+    ] {
+        assert!(matches!(
+            u64::from_cadence_value(&signed),
+            Err(serde_cadence::Error::TypeMismatch { .. })
+        ));
+    }
+}
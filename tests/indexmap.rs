@@ -0,0 +1,55 @@
+// tests/indexmap.rs
+//
+// IndexMap keeps dictionary entry order in both directions
+
+#![cfg(feature = "indexmap")]
+
+use indexmap::IndexMap;
+use serde_cadence::{CadenceValue, DictionaryEntry, FromCadenceValue, ToCadenceValue};
+
+fn string(value: &str) -> CadenceValue {
+    CadenceValue::String {
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn index_maps_keep_insertion_order() {
+    let mut map = IndexMap::new();
+    map.insert("zebra".to_string(), 1u8);
+    map.insert("apple".to_string(), 2);
+    map.insert("mango".to_string(), 3);
+
+    let value = map.to_cadence_value().unwrap();
+    let CadenceValue::Dictionary { value: entries } = &value else {
+        panic!("expected a Dictionary, got {:?}", value);
+    };
+    let keys: Vec<_> = entries.iter().map(|entry| entry.key.clone()).collect();
+    assert_eq!(
+        keys,
+        vec![string("zebra"), string("apple"), string("mango")]
+    );
+
+    let back = IndexMap::<String, u8>::from_cadence_value(&value).unwrap();
+    assert_eq!(back, map);
+    assert!(back.keys().eq(map.keys()));
+}
+
+#[test]
+fn index_maps_decode_in_entry_order() {
+    let value = CadenceValue::Dictionary {
+        value: vec![
+            DictionaryEntry {
+                key: string("b"),
+                value: string("second"),
+            },
+            DictionaryEntry {
+                key: string("a"),
+                value: string("first"),
+            },
+        ],
+    };
+    let map = IndexMap::<String, String>::from_cadence_value(&value).unwrap();
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec!["b", "a"]);
+    assert!(IndexMap::<String, String>::from_cadence_value(&string("a")).is_err());
+}
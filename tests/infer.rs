@@ -0,0 +1,123 @@
+// tests/infer.rs
+//
+// Inferring a CadenceType from a CadenceValue
+
+use serde_cadence::{
+    CadenceType, CadenceValue, CompositeField, CompositeValue, DictionaryEntry, FieldType,
+    ToCadenceValue,
+};
+
+fn ty(s: &str) -> CadenceType {
+    CadenceType::parse(s).unwrap()
+}
+
+fn resource(id: &str) -> CadenceValue {
+    CadenceValue::Resource {
+        value: CompositeValue {
+            id: id.into(),
+            fields: vec![CompositeField {
+                name: "id".into(),
+                value: CadenceValue::UInt64 {
+                    value: "1".to_string(),
+                },
+            }],
+        },
+    }
+}
+
+#[test]
+fn leaves_infer_their_own_type() {
+    assert_eq!(
+        true.to_cadence_value().unwrap().infer_type(),
+        CadenceType::Bool
+    );
+    assert_eq!(
+        7u16.to_cadence_value().unwrap().infer_type(),
+        CadenceType::UInt16
+    );
+    assert_eq!(
+        "hi".to_cadence_value().unwrap().infer_type(),
+        CadenceType::String
+    );
+    assert_eq!(CadenceValue::Void {}.infer_type(), CadenceType::Void);
+}
+
+#[test]
+fn containers_unify_their_contents() {
+    let numbers = vec![1i32, 2, 3].to_cadence_value().unwrap();
+    assert_eq!(numbers.infer_type(), ty("[Int32]"));
+
+    let empty: Vec<u8> = Vec::new();
+    assert_eq!(
+        empty.to_cadence_value().unwrap().infer_type(),
+        ty("[Never]")
+    );
+
+    let mixed = CadenceValue::Array {
+        value: vec![
+            CadenceValue::Bool { value: true },
+            CadenceValue::String {
+                value: "x".to_string(),
+            },
+        ],
+    };
+    assert_eq!(mixed.infer_type(), ty("[AnyStruct]"));
+
+    let dictionary = CadenceValue::Dictionary {
+        value: vec![DictionaryEntry {
+            key: CadenceValue::String {
+                value: "a".to_string(),
+            },
+            value: CadenceValue::Int {
+                value: "1".to_string(),
+            },
+        }],
+    };
+    assert_eq!(dictionary.infer_type(), ty("{String: Int}"));
+}
+
+#[test]
+fn optionals_unify_with_nil() {
+    let values = vec![Some(1u8), None].to_cadence_value().unwrap();
+    assert_eq!(values.infer_type(), ty("[UInt8?]"));
+    assert_eq!(
+        CadenceValue::Optional { value: None }.infer_type(),
+        ty("Never?")
+    );
+}
+
+#[test]
+fn resources_unify_to_any_resource() {
+    let resources = CadenceValue::Array {
+        value: vec![resource("A.0x1.NFT.A"), resource("A.0x1.NFT.B")],
+    };
+    assert_eq!(resources.infer_type(), ty("[AnyResource]"));
+
+    let mixed = CadenceValue::Array {
+        value: vec![resource("A.0x1.NFT.A"), CadenceValue::Bool { value: true }],
+    };
+    assert_eq!(mixed.infer_type(), ty("[AnyStruct]"));
+}
+
+#[test]
+fn composites_keep_their_id_and_field_types() {
+    match resource("A.0x1.NFT.A").infer_type() {
+        CadenceType::Resource {
+            type_id,
+            initializers,
+            fields,
+            ..
+        } => {
+            assert_eq!(type_id, "A.0x1.NFT.A");
+            assert!(initializers.is_empty());
+            assert_eq!(
+                fields,
+                vec![FieldType {
+                    id: "id".to_string(),
+                    type_: CadenceType::UInt64,
+                }]
+            );
+        }
+        other => panic!("expected a Resource type, got {:?}", other),
+    }
+}
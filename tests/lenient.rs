@@ -0,0 +1,45 @@
+// tests/lenient.rs
+//
+// Lenient decoding of numbers and addresses into strings
+
+use serde_cadence::{CadenceValue, FromCadenceValue, Lenient, ToCadenceValue};
+
+fn address() -> CadenceValue {
+    CadenceValue::Address {
+        value: "0x0000000000000001".to_string(),
+    }
+}
+
+#[test]
+fn plain_strings_only_accept_strings() {
+    assert!(String::from_cadence_value(&address()).is_err());
+    assert!(String::from_cadence_value(&5u8.to_cadence_value().unwrap()).is_err());
+}
+
+#[test]
+fn lenient_strings_accept_addresses_and_numbers() {
+    assert_eq!(
+        Lenient::<String>::from_cadence_value(&address()).unwrap().0,
+        "0x0000000000000001"
+    );
+    assert_eq!(
+        Lenient::<String>::from_cadence_value(&(-5i64).to_cadence_value().unwrap())
+            .unwrap()
+            .into_inner(),
+        "-5"
+    );
+    let amount = CadenceValue::UFix64 {
+        value: "1.50000000".to_string(),
+    };
+    assert_eq!(
+        *Lenient::<String>::from_cadence_value(&amount).unwrap(),
+        "1.50000000"
+    );
+    assert!(Lenient::<String>::from_cadence_value(&CadenceValue::Bool { value: true }).is_err());
+
+    // Encoding goes through the inner type
+    assert_eq!(
+        Lenient("x".to_string()).to_cadence_value().unwrap(),
+        "x".to_cadence_value().unwrap()
+    );
+}
@@ -0,0 +1,108 @@
+// tests/macros.rs
+//
+// CadenceValue literals built with cadence_value!
+
+use serde_cadence::{CadenceValue, DictionaryEntry, cadence_value};
+
+fn string(value: &str) -> CadenceValue {
+    CadenceValue::String {
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn scalars_follow_the_literal_type() {
+    assert_eq!(
+        cadence_value!(42u64),
+        CadenceValue::UInt64 {
+            value: "42".to_string()
+        }
+    );
+    assert_eq!(
+        cadence_value!(-1i8),
+        CadenceValue::Int8 {
+            value: "-1".to_string()
+        }
+    );
+    assert_eq!(
+        cadence_value!(42),
+        CadenceValue::Int32 {
+            value: "42".to_string()
+        }
+    );
+    assert_eq!(cadence_value!(true), CadenceValue::Bool { value: true });
+    assert_eq!(cadence_value!("hi"), string("hi"));
+    assert_eq!(cadence_value!(null), CadenceValue::Optional { value: None });
+
+    let name = String::from("flow");
+    assert_eq!(cadence_value!(name), string("flow"));
+}
+
+#[test]
+fn arrays_and_dictionaries_nest() {
+    let value =
+        cadence_value!({ "apple" => [1u8, 2u8], "banana" => null, "cherry" => { "x" => [] } });
+    let expected = CadenceValue::Dictionary {
+        value: vec![
+            DictionaryEntry {
+                key: string("apple"),
+                value: CadenceValue::Array {
+                    value: vec![
+                        CadenceValue::UInt8 {
+                            value: "1".to_string(),
+                        },
+                        CadenceValue::UInt8 {
+                            value: "2".to_string(),
+                        },
+                    ],
+                },
+            },
+            DictionaryEntry {
+                key: string("banana"),
+                value: CadenceValue::Optional { value: None },
+            },
+            DictionaryEntry {
+                key: string("cherry"),
+                value: CadenceValue::Dictionary {
+                    value: vec![DictionaryEntry {
+                        key: string("x"),
+                        value: CadenceValue::Array { value: vec![] },
+                    }],
+                },
+            },
+        ],
+    };
+    assert_eq!(value, expected);
+}
+
+#[test]
+fn trailing_commas_and_empty_containers() {
+    assert_eq!(cadence_value!([]), CadenceValue::Array { value: vec![] });
+    assert_eq!(
+        cadence_value!({}),
+        CadenceValue::Dictionary { value: vec![] }
+    );
+    assert_eq!(
+        cadence_value!([true, null, [false],]),
+        CadenceValue::Array {
+            value: vec![
+                CadenceValue::Bool { value: true },
+                CadenceValue::Optional { value: None },
+                CadenceValue::Array {
+                    value: vec![CadenceValue::Bool { value: false }],
+                },
+            ],
+        }
+    );
+    assert_eq!(
+        cadence_value!({ 1u8 => "one", }),
+        CadenceValue::Dictionary {
+            value: vec![DictionaryEntry {
+                key: CadenceValue::UInt8 {
+                    value: "1".to_string(),
+                },
+                value: string("one"),
+            }],
+        }
+    );
+}
@@ -0,0 +1,155 @@
+// tests/parse.rs
+//
+// Parsing Cadence-JSON text with from_str, from_slice and from_reader
+
+use serde_cadence::{
+    CadenceValue, CadenceValueRef, ParseOptions, from_str_borrowed, from_str_value,
+    from_str_value_with,
+};
+use std::borrow::Cow;
+
+const DEEP: usize = 10_000;
+
+// `depth` arrays nested inside each other, as Cadence-JSON text
+fn nested_arrays(depth: usize) -> String {
+    let mut json = String::new();
+    for _ in 0..depth {
+        json.push_str(r#"{"type":"Array","value":["#);
+    }
+    json.push_str(r#"{"type":"Bool","value":true}"#);
+    for _ in 0..depth {
+        json.push_str("]}");
+    }
+    json
+}
+
+#[test]
+fn deeply_nested_input_errors_instead_of_overflowing() {
+    let json = nested_arrays(DEEP);
+    assert!(serde_cadence::from_str::<CadenceValue>(&json).is_err());
+    assert!(serde_cadence::from_slice::<CadenceValue>(json.as_bytes()).is_err());
+    assert!(serde_cadence::from_str_value(&json).is_err());
+    assert!(serde_cadence::from_str_borrowed(&json).is_err());
+    #[cfg(feature = "std")]
+    assert!(serde_cadence::from_reader::<_, CadenceValue>(json.as_bytes()).is_err());
+}
+
+#[test]
+fn moderately_nested_input_parses() {
+    let value: CadenceValue = serde_cadence::from_str(&nested_arrays(20)).unwrap();
+    assert!(value.get(&"0/".repeat(20)).is_some());
+}
+
+const DOCUMENT: &str = r#"{"type":"Struct","value":{"id":"A.0x1.Token","fields":[
+    {"name":"owner","value":{"type":"Address","value":"0x01"}},
+    {"name":"note","value":{"type":"String","value":"say \"hi\""}},
+    {"name":"tags","value":{"type":"Dictionary","value":[
+        {"key":{"type":"String","value":"a"},"value":{"value":"7","type":"UInt8"}}
+    ]}},
+    {"name":"extra","value":{"type":"Optional","value":null}},
+    {"name":"path","value":{"type":"Path","value":{"domain":"storage","identifier":"vault"}}}
+]}}"#;
+
+#[test]
+fn borrowed_and_owned_parsing_agree() {
+    let owned = from_str_value(DOCUMENT).unwrap();
+    let borrowed = from_str_borrowed(DOCUMENT).unwrap();
+    assert_eq!(borrowed.clone().into_owned(), owned);
+    assert_eq!(
+        serde_cadence::from_slice_value(DOCUMENT.as_bytes()).unwrap(),
+        owned
+    );
+
+    let CadenceValueRef::Struct { value } = borrowed else {
+        panic!("expected a Struct");
+    };
+    assert!(matches!(value.id, Cow::Borrowed("A.0x1.Token")));
+    // Strings with escapes can't be borrowed from the input
+    match &value.fields[1].value {
+        CadenceValueRef::String { value } => {
+            assert!(matches!(value, Cow::Owned(_)));
+            assert_eq!(value, "say \"hi\"");
+        }
+        other => panic!("expected a String, got {:?}", other),
+    }
+}
+
+#[test]
+fn borrowed_and_owned_parsing_reject_the_same_input() {
+    for json in [
+        r#"{"type":"Nope","value":1}"#,
+        r#"{"type":"Int"}"#,
+        r#"{"value":"1"}"#,
+        r#"{"type":"Int","value":"1","value":"2"}"#,
+        r#"{"type":"Array","value":[{"type":"Bool"}]}"#,
+        r#"{"type":"Bool","value":true} trailing"#,
+    ] {
+        let owned = from_str_value(json).unwrap_err().to_string();
+        let borrowed = from_str_borrowed(json).unwrap_err().to_string();
+        assert_eq!(owned, borrowed, "{}", json);
+    }
+    assert!(
+        from_str_value(r#"{"type":"Nope","value":1}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("unknown variant `Nope`")
+    );
+}
+
+// The keys are equal once their fixed-point values are canonicalized
+const DUPLICATE_KEYS: &str = r#"{"type":"Dictionary","value":[
+    {"key":{"type":"UFix64","value":"1.5"},"value":{"type":"Bool","value":true}},
+    {"key":{"type":"UFix64","value":"1.50000000"},"value":{"type":"Bool","value":false}}
+]}"#;
+
+#[test]
+fn duplicate_keys_are_rejected_only_when_asked() {
+    let value = from_str_value(DUPLICATE_KEYS).unwrap();
+    assert!(value.check_dictionary_keys().is_err());
+
+    let options = ParseOptions { unique_keys: true };
+    let error = from_str_value_with(DUPLICATE_KEYS, &options).unwrap_err();
+    assert!(
+        error.to_string().contains("duplicate dictionary key"),
+        "{}",
+        error
+    );
+    assert!(serde_cadence::from_slice_value_with(DUPLICATE_KEYS.as_bytes(), &options).is_err());
+    #[cfg(feature = "std")]
+    assert!(serde_cadence::from_reader_value_with(DUPLICATE_KEYS.as_bytes(), &options).is_err());
+
+    let nested = format!(
+        r#"{{"type":"Array","value":[{{"type":"Optional","value":{}}}]}}"#,
+        DUPLICATE_KEYS
+    );
+    assert!(from_str_value_with(&nested, &options).is_err());
+    assert_eq!(
+        from_str_value_with(DOCUMENT, &options).unwrap(),
+        from_str_value(DOCUMENT).unwrap()
+    );
+}
+
+const DUPLICATE_STRING_KEYS: &str = r#"{"type":"Dictionary","value":[
+    {"key":{"type":"String","value":"a"},"value":{"type":"Int","value":"1"}},
+    {"key":{"type":"String","value":"a"},"value":{"type":"Int","value":"2"}}
+]}"#;
+
+#[test]
+fn identical_string_keys_are_duplicates() {
+    // Both entries are kept by default
+    let value = from_str_value(DUPLICATE_STRING_KEYS).unwrap();
+    assert_eq!(value.as_dictionary().map(<[_]>::len), Some(2));
+    assert!(value.check_dictionary_keys().is_err());
+
+    let options = ParseOptions { unique_keys: true };
+    let error = from_str_value_with(DUPLICATE_STRING_KEYS, &options).unwrap_err();
+    assert!(
+        error.to_string().contains("duplicate dictionary key"),
+        "{}",
+        error
+    );
+
+    // Keys of different types don't collide
+    let distinct = DUPLICATE_STRING_KEYS.replacen(r#""type":"String""#, r#""type":"Character""#, 1);
+    assert!(from_str_value_with(&distinct, &options).is_ok());
+}
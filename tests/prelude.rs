@@ -0,0 +1,32 @@
+// tests/prelude.rs
+//
+// The prelude alone is enough to encode, decode and derive
+
+use serde_cadence::prelude::*;
+
+#[test]
+fn prelude_brings_the_traits() {
+    let value: CadenceValue = 5u8.to_cadence_value().unwrap();
+    let decoded: Result<u8> = u8::from_cadence_value(&value);
+    assert_eq!(decoded.unwrap(), 5);
+    assert!(matches!(
+        String::from_cadence_value(&value),
+        Err(Error::TypeMismatch { .. })
+    ));
+    assert_eq!(CadenceType::parse("UInt8").unwrap(), CadenceType::UInt8);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn prelude_brings_the_derives() {
+    #[derive(Debug, PartialEq, ToCadenceValue, FromCadenceValue)]
+    struct Counter {
+        count: u32,
+    }
+
+    let value = Counter { count: 2 }.to_cadence_value().unwrap();
+    assert_eq!(
+        Counter::from_cadence_value(&value).unwrap(),
+        Counter { count: 2 }
+    );
+}
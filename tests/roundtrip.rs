@@ -0,0 +1,87 @@
+// tests/roundtrip.rs
+//
+// Property tests for the round-trip invariants of the parsers and the plain
+// JSON conversions
+
+use proptest::prelude::*;
+use serde_cadence::{
+    CadenceValue, DictionaryEntry, cadence_value_to_value, value_to_cadence_value,
+};
+
+// Values that survive a trip through plain JSON: type tags that JSON can't
+// carry (UInt8, Struct, Some(..)) are left out, integers stay in `Int`'s
+// i64 range and dictionaries have distinct String keys
+fn plain_value() -> impl Strategy<Value = CadenceValue> {
+    let leaf = prop_oneof![
+        Just(CadenceValue::Optional { value: None }),
+        any::<bool>().prop_map(|value| CadenceValue::Bool { value }),
+        any::<String>().prop_map(|value| CadenceValue::String { value }),
+        any::<i64>().prop_map(|value| CadenceValue::Int {
+            value: value.to_string()
+        }),
+    ];
+    leaf.prop_recursive(4, 32, 4, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..4)
+                .prop_map(|value| CadenceValue::Array { value }),
+            prop::collection::btree_map(any::<String>(), inner, 0..4).prop_map(|map| {
+                CadenceValue::Dictionary {
+                    value: map
+                        .into_iter()
+                        .map(|(key, value)| DictionaryEntry {
+                            key: CadenceValue::String { value: key },
+                            value,
+                        })
+                        .collect(),
+                }
+            }),
+        ]
+    })
+}
+
+proptest! {
+    #[test]
+    fn plain_json_round_trips(value in plain_value()) {
+        let json = cadence_value_to_value(&value).unwrap();
+        let back = value_to_cadence_value(&json).unwrap();
+        prop_assert_eq!(back.canonical(), value.canonical());
+    }
+
+    #[test]
+    fn cadence_json_round_trips(value in plain_value()) {
+        let json = serde_json::to_string(&value).unwrap();
+        prop_assert_eq!(serde_cadence::from_str_value(&json).unwrap(), value);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_values {
+    use arbitrary::{Arbitrary, Unstructured};
+    use proptest::prelude::*;
+    use serde_cadence::{CadenceValue, ParseOptions};
+
+    proptest! {
+        // Every value the Arbitrary impl produces survives Cadence-JSON, and
+        // the streaming and borrowing parsers agree with the derived
+        // Deserialize on it
+        #[test]
+        fn parsers_agree(data in prop::collection::vec(any::<u8>(), 0..512)) {
+            let Ok(value) = CadenceValue::arbitrary(&mut Unstructured::new(&data)) else {
+                return Ok(());
+            };
+            let json = serde_json::to_string(&value).unwrap();
+
+            let derived: CadenceValue = serde_json::from_str(&json).unwrap();
+            prop_assert_eq!(&derived, &value);
+            prop_assert_eq!(&serde_cadence::from_str_value(&json).unwrap(), &value);
+            // Duplicate keys from the generator are only rejected when asked
+            let options = ParseOptions { unique_keys: true };
+            match serde_cadence::from_str_value_with(&json, &options) {
+                Ok(checked) => prop_assert_eq!(&checked, &value),
+                Err(_) => prop_assert!(value.check_dictionary_keys().is_err()),
+            }
+            let borrowed = serde_cadence::from_str_borrowed(&json).unwrap().into_owned();
+            prop_assert_eq!(&borrowed, &value);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// tests/schema.rs
+//
+// Checking values against types with CadenceValue::validate_against
+
+use serde_cadence::{CadenceType, CadenceValue, Error, FieldType, ToCadenceValue};
+
+fn ty(type_id: &str) -> CadenceType {
+    CadenceType::parse(type_id).unwrap()
+}
+
+fn point_type() -> CadenceType {
+    CadenceType::struct_type(
+        "A.0000000000000001.Geo.Point",
+        vec![],
+        vec![
+            FieldType::new("x", CadenceType::Int),
+            FieldType::new("tags", ty("[String]")),
+        ],
+    )
+}
+
+fn point(tags: CadenceValue) -> CadenceValue {
+    serde_cadence::from_str_value(&format!(
+        r#"{{"type":"Struct","value":{{"id":"A.0000000000000001.Geo.Point","fields":[
+            {{"name":"x","value":{{"type":"Int","value":"1"}}}},
+            {{"name":"tags","value":{}}}
+        ]}}}}"#,
+        serde_json::to_string(&tags).unwrap()
+    ))
+    .unwrap()
+}
+
+#[test]
+fn matching_values_validate() {
+    let checks: Vec<(CadenceValue, &str)> = vec![
+        (5u8.to_cadence_value().unwrap(), "UInt8"),
+        (5u8.to_cadence_value().unwrap(), "Integer"),
+        ((-5i32).to_cadence_value().unwrap(), "SignedNumber"),
+        (Some(true).to_cadence_value().unwrap(), "Bool?"),
+        (None::<bool>.to_cadence_value().unwrap(), "Bool?"),
+        (vec!["a", "b"].to_cadence_value().unwrap(), "[String]"),
+        ("x".to_cadence_value().unwrap(), "AnyStruct"),
+    ];
+    for (value, type_id) in checks {
+        value.validate_against(&ty(type_id)).unwrap();
+    }
+    point(vec!["a"].to_cadence_value().unwrap())
+        .validate_against(&point_type())
+        .unwrap();
+}
+
+#[test]
+fn mismatches_name_the_offending_path() {
+    assert!(
+        true.to_cadence_value()
+            .unwrap()
+            .validate_against(&ty("String"))
+            .is_err()
+    );
+    assert!(
+        5u8.to_cadence_value()
+            .unwrap()
+            .validate_against(&ty("SignedInteger"))
+            .is_err()
+    );
+
+    let bad_tag = point(vec![1u8].to_cadence_value().unwrap());
+    match bad_tag.validate_against(&point_type()) {
+        Err(Error::TypeMismatch { expected, .. }) => assert_eq!(expected, "String at `tags/0`"),
+        other => panic!("expected a type mismatch, got {:?}", other),
+    }
+
+    let wrong_id = CadenceType::struct_type("A.0000000000000001.Geo.Line", vec![], vec![]);
+    assert!(
+        point(vec!["a"].to_cadence_value().unwrap())
+            .validate_against(&wrong_id)
+            .is_err()
+    );
+}
+
+#[test]
+fn constant_sized_arrays_check_their_length() {
+    let three = vec![1u8, 2, 3].to_cadence_value().unwrap();
+    three.validate_against(&ty("[UInt8; 3]")).unwrap();
+    match three.validate_against(&ty("[UInt8; 2]")) {
+        Err(Error::InvalidCadenceValue(message)) => {
+            assert_eq!(message, "expected 2 elements for [UInt8; 2] at `/`, got 3")
+        }
+        other => panic!("expected a length error, got {:?}", other),
+    }
+    assert!(three.validate_against(&ty("[UInt16; 3]")).is_err());
+}
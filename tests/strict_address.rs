@@ -0,0 +1,54 @@
+// tests/strict_address.rs
+//
+// Malformed addresses are rejected while parsing with the strict-address
+// feature, whichever parser is used
+
+#![cfg(feature = "strict-address")]
+
+use serde_cadence::{CadenceValue, from_str_borrowed, from_str_value};
+
+fn address_json(address: &str) -> String {
+    format!(r#"{{"type":"Address","value":"{}"}}"#, address)
+}
+
+#[test]
+fn well_formed_addresses_parse() {
+    for address in ["0x1", "0xf8d6e0586b0a20c7", "0xABCDEF"] {
+        assert_eq!(
+            from_str_value(&address_json(address)).unwrap(),
+            CadenceValue::Address {
+                value: address.to_string()
+            }
+        );
+        assert!(from_str_borrowed(&address_json(address)).is_ok());
+        let value: CadenceValue = serde_json::from_str(&address_json(address)).unwrap();
+        assert!(matches!(value, CadenceValue::Address { .. }));
+    }
+}
+
+#[test]
+fn malformed_addresses_are_rejected() {
+    for address in [
+        "",
+        "0x",
+        "f8d6e0586b0a20c7",
+        "0xzz",
+        "0x10000000000000000",
+        "1x01",
+    ] {
+        let json = address_json(address);
+        let error = from_str_value(&json).unwrap_err();
+        assert!(
+            error.to_string().contains("invalid Cadence address"),
+            "{}: {}",
+            address,
+            error
+        );
+        assert!(from_str_borrowed(&json).is_err(), "{}", address);
+        assert!(
+            serde_json::from_str::<CadenceValue>(&json).is_err(),
+            "{}",
+            address
+        );
+    }
+}
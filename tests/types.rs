@@ -0,0 +1,213 @@
+// tests/types.rs
+//
+// Behaviour of CadenceType and of values annotated with their static type
+
+use serde_cadence::{
+    Authorization, CadenceType, CadenceValue, Entitlement, TypedValue, to_cadence_value_typed,
+};
+
+#[test]
+fn typed_value_carries_static_type() {
+    let elements: Vec<u8> = Vec::new();
+    let array_type = CadenceType::VariableSizedArray {
+        type_: Box::new(CadenceType::UInt8),
+    };
+    let typed = to_cadence_value_typed(&elements, array_type.clone()).unwrap();
+    assert_eq!(typed.value, CadenceValue::Array { value: vec![] });
+
+    let json = serde_json::to_string(&typed).unwrap();
+    assert_eq!(
+        json,
+        r#"{"type":"Array","value":[],"staticType":{"kind":"VariableSizedArray","type":{"kind":"UInt8"}}}"#
+    );
+    let decoded: TypedValue = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, typed);
+}
+
+#[test]
+fn typed_value_rejects_mismatched_type() {
+    assert!(to_cadence_value_typed(&true, CadenceType::String).is_err());
+    assert!(to_cadence_value_typed(&vec![1u8], CadenceType::Bool).is_err());
+}
+
+#[test]
+fn type_id_renders_nested_types() {
+    let dictionary = CadenceType::Dictionary {
+        key: Box::new(CadenceType::Address),
+        value: Box::new(CadenceType::Optional {
+            type_: Box::new(CadenceType::VariableSizedArray {
+                type_: Box::new(CadenceType::UInt64),
+            }),
+        }),
+    };
+    assert_eq!(dictionary.type_id(), "{Address: [UInt64]?}");
+    assert_eq!(dictionary.to_string(), dictionary.type_id());
+
+    let fixed = CadenceType::ConstantSizedArray {
+        type_: Box::new(CadenceType::String),
+        size: 3,
+    };
+    assert_eq!(fixed.type_id(), "[String; 3]");
+
+    let capability = CadenceType::Capability {
+        type_: Box::new(CadenceType::reference(CadenceType::struct_type(
+            "A.0000000000000001.Foo.Bar",
+            vec![],
+            vec![],
+        ))),
+    };
+    assert_eq!(
+        capability.type_id(),
+        "Capability<&A.0000000000000001.Foo.Bar>"
+    );
+}
+
+#[test]
+fn type_id_renders_authorized_references() {
+    let reference = CadenceType::auth_reference(["Withdraw", "Deposit"], CadenceType::Int);
+    assert_eq!(reference.type_id(), "auth(Withdraw, Deposit) &Int");
+}
+
+#[test]
+fn parse_round_trips_type_ids() {
+    for type_id in [
+        "{String: [UInt64]}",
+        "[Int8; 4]?",
+        "Capability<&A.0000000000000001.Foo.Bar>",
+        "InclusiveRange<UInt>",
+        "auth(A.1.Withdraw, A.1.Deposit) &Int",
+        "auth(A.1.Withdraw | A.1.Deposit) &Int",
+        "auth(mapping A.1.Map) &{A.1.Receiver}",
+    ] {
+        let parsed = CadenceType::parse(type_id).unwrap();
+        assert_eq!(parsed.type_id(), type_id);
+    }
+    assert_eq!(CadenceType::parse(" Int ? ").unwrap().type_id(), "Int?");
+}
+
+#[test]
+fn parse_rejects_malformed_type_ids() {
+    for type_id in [
+        "",
+        "[Int",
+        "{String: Int",
+        "Int]",
+        "Capability<Int",
+        "[Int; x]",
+    ] {
+        assert!(CadenceType::parse(type_id).is_err(), "{:?}", type_id);
+    }
+}
+
+#[test]
+fn parse_rejects_mixed_authorization_separators() {
+    assert!(CadenceType::parse("auth(A, B | C) &Int").is_err());
+    assert!(CadenceType::parse("auth(A | B, C) &Int").is_err());
+}
+
+#[test]
+fn parse_limits_nesting_depth() {
+    let deep = format!("{}Int{}", "[".repeat(10_000), "]".repeat(10_000));
+    assert!(CadenceType::parse(&deep).is_err());
+    let references = format!("{}Int", "&".repeat(10_000));
+    assert!(CadenceType::parse(&references).is_err());
+
+    let shallow = format!("{}Int{}", "[".repeat(100), "]".repeat(100));
+    assert!(CadenceType::parse(&shallow).is_ok());
+}
+
+#[test]
+fn entitlements_deserialize_by_kind() {
+    let json = r#"{
+        "kind": "Reference",
+        "authorization": {
+            "kind": "EntitlementConjunctionSet",
+            "entitlements": [
+                {"kind": "Entitlement", "typeID": "A.1.Vault.Withdraw"},
+                {"kind": "EntitlementMap", "typeID": "A.1.Vault.Map"}
+            ]
+        },
+        "type": {"kind": "Int"}
+    }"#;
+    let parsed: CadenceType = serde_json::from_str(json).unwrap();
+    let CadenceType::Reference {
+        authorization: Authorization::EntitlementConjunctionSet { entitlements },
+        ..
+    } = &parsed
+    else {
+        panic!("expected an authorized reference, got {:?}", parsed);
+    };
+    assert_eq!(
+        entitlements,
+        &[
+            Entitlement::Plain {
+                type_id: "A.1.Vault.Withdraw".to_string()
+            },
+            Entitlement::EntitlementMap {
+                type_id: "A.1.Vault.Map".to_string()
+            },
+        ]
+    );
+
+    let encoded = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(
+        encoded["authorization"]["entitlements"][0]["kind"],
+        "Entitlement"
+    );
+    assert_eq!(
+        serde_json::from_value::<CadenceType>(encoded).unwrap(),
+        parsed
+    );
+}
+
+#[test]
+fn reference_constructors_build_references() {
+    assert_eq!(
+        CadenceType::reference(CadenceType::String),
+        CadenceType::Reference {
+            authorization: Authorization::Unauthorized { entitlements: None },
+            type_: Box::new(CadenceType::String),
+        }
+    );
+    assert_eq!(
+        CadenceType::auth_reference(["A.1.Withdraw"], CadenceType::UFix64),
+        CadenceType::Reference {
+            authorization: Authorization::EntitlementConjunctionSet {
+                entitlements: vec![Entitlement::Plain {
+                    type_id: "A.1.Withdraw".to_string()
+                }],
+            },
+            type_: Box::new(CadenceType::UFix64),
+        }
+    );
+}
+
+#[test]
+fn unauthorized_references_accept_missing_entitlements() {
+    let expected = CadenceType::Reference {
+        authorization: Authorization::Unauthorized { entitlements: None },
+        type_: Box::new(CadenceType::Int),
+    };
+    for json in [
+        r#"{"kind":"Reference","authorization":{"kind":"Unauthorized"},"type":{"kind":"Int"}}"#,
+        r#"{"kind":"Reference","authorization":{"kind":"Unauthorized","entitlements":null},"type":{"kind":"Int"}}"#,
+    ] {
+        assert_eq!(serde_json::from_str::<CadenceType>(json).unwrap(), expected);
+    }
+
+    let json = serde_json::to_value(&expected).unwrap();
+    assert_eq!(json["type"], serde_json::json!({"kind": "Int"}));
+    assert_eq!(
+        serde_json::from_value::<CadenceType>(json).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn display_matches_type_id() {
+    for id in ["Int", "[UInt8]", "{String: [Int]?}", "auth(A.1.X.Y) &Int"] {
+        let ty = CadenceType::parse(id).unwrap();
+        assert_eq!(ty.to_string(), ty.type_id());
+        assert_eq!(format!("{}", ty), id);
+    }
+}
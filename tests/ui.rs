@@ -0,0 +1,11 @@
+// tests/ui.rs
+//
+// Compile errors reported by the derive macros and assertion macros
+
+#![cfg(feature = "derive")]
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
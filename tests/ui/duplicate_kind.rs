@@ -0,0 +1,10 @@
+use serde_cadence::FromCadenceValue;
+
+#[derive(FromCadenceValue)]
+#[cadence(kind = "Event")]
+#[cadence(kind = "Struct")]
+struct Point {
+    x: i64,
+}
+
+fn main() {}
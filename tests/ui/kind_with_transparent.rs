@@ -0,0 +1,9 @@
+use serde_cadence::ToCadenceValue;
+
+#[derive(ToCadenceValue)]
+#[cadence(kind = "Event", transparent)]
+struct Amount {
+    value: u64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde_cadence::FromCadenceValue;
+
+#[derive(FromCadenceValue)]
+#[cadence(repr = "string")]
+enum Listing {
+    Open,
+    Sold { price: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde_cadence::ToCadenceValue;
+
+#[derive(ToCadenceValue)]
+#[cadence(repr = "string")]
+enum Status {
+    Active,
+    Banned(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde_cadence::{ToCadenceValue, assert_cadence_roundtrip};
+
+#[derive(ToCadenceValue)]
+struct Receipt {
+    amount: u64,
+}
+
+assert_cadence_roundtrip!(u64, Receipt);
+
+fn main() {}
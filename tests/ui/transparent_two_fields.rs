@@ -0,0 +1,10 @@
+use serde_cadence::ToCadenceValue;
+
+#[derive(ToCadenceValue)]
+#[cadence(transparent)]
+struct Pair {
+    a: u8,
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_cadence::ToCadenceValue;
+
+#[derive(ToCadenceValue)]
+#[cadence(rename = "Other")]
+struct Point {
+    x: i64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_cadence::FromCadenceValue;
+
+#[derive(FromCadenceValue)]
+struct Point {
+    #[cadence(skip)]
+    x: i64,
+}
+
+fn main() {}
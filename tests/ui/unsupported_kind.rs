@@ -0,0 +1,9 @@
+use serde_cadence::ToCadenceValue;
+
+#[derive(ToCadenceValue)]
+#[cadence(kind = "Attachment")]
+struct Point {
+    x: i64,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+// tests/uuid.rs
+//
+// Uuid values encoded as hyphenated Strings
+
+#![cfg(feature = "uuid")]
+
+use serde_cadence::{CadenceValue, FromCadenceValue, ToCadenceValue};
+use uuid::Uuid;
+
+#[test]
+fn uuids_round_trip_as_hyphenated_strings() {
+    let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+    let value = id.to_cadence_value().unwrap();
+    assert_eq!(
+        value,
+        CadenceValue::String {
+            value: "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string()
+        }
+    );
+    assert_eq!(Uuid::from_cadence_value(&value).unwrap(), id);
+}
+
+#[test]
+fn uuids_reject_other_values() {
+    let not_a_uuid = CadenceValue::String {
+        value: "67e55044".to_string(),
+    };
+    assert!(Uuid::from_cadence_value(&not_a_uuid).is_err());
+    assert!(Uuid::from_cadence_value(&CadenceValue::Bool { value: true }).is_err());
+}
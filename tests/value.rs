@@ -0,0 +1,545 @@
+// tests/value.rs
+//
+// Inherent helpers on CadenceValue
+
+use serde_cadence::{
+    CadenceType, CadenceValue, Canonical, CompositeValue, DictionaryEntry, Error, ToCadenceValue,
+    UFix64,
+};
+use std::collections::HashSet;
+
+#[test]
+fn fixed_point_accessors_are_exact() {
+    let max = CadenceValue::UFix64 {
+        value: "184467440737.09551615".to_string(),
+    };
+    assert_eq!(max.as_fixed_point(), Some(i128::from(u64::MAX)));
+    assert_eq!(
+        CadenceValue::fixed_point_from_scaled(i128::from(u64::MAX), &CadenceType::UFix64).unwrap(),
+        max
+    );
+
+    assert_eq!(
+        CadenceValue::fixed_point_from_scaled(-150_000_000, &CadenceType::Fix64).unwrap(),
+        CadenceValue::Fix64 {
+            value: "-1.50000000".to_string()
+        }
+    );
+    assert_eq!(
+        CadenceValue::fixed_point_from_scaled(1, &CadenceType::Fix64).unwrap(),
+        CadenceValue::Fix64 {
+            value: "0.00000001".to_string()
+        }
+    );
+}
+
+#[test]
+fn fixed_point_from_scaled_checks_range_and_type() {
+    let too_big = i128::from(u64::MAX) + 1;
+    assert!(CadenceValue::fixed_point_from_scaled(too_big, &CadenceType::UFix64).is_err());
+    assert!(CadenceValue::fixed_point_from_scaled(-1, &CadenceType::UFix64).is_err());
+    let past_fix64 = i128::from(i64::MAX) + 1;
+    assert!(CadenceValue::fixed_point_from_scaled(past_fix64, &CadenceType::Fix64).is_err());
+    assert!(CadenceValue::fixed_point_from_scaled(1, &CadenceType::Int).is_err());
+}
+
+fn nft() -> CadenceValue {
+    serde_cadence::from_str(
+        r#"{"type":"Resource","value":{"id":"NFT","fields":[
+            {"name":"id","value":{"type":"UInt64","value":"7"}},
+            {"name":"metadata","value":{"type":"Struct","value":{"id":"Metadata","fields":[
+                {"name":"tags","value":{"type":"Array","value":[
+                    {"type":"String","value":"rare"},
+                    {"type":"String","value":"blue"}
+                ]}}
+            ]}}}
+        ]}}"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn get_follows_fields_and_indices() {
+    let nft = nft();
+    assert_eq!(
+        nft.get("metadata/tags/1"),
+        Some(&CadenceValue::String {
+            value: "blue".to_string()
+        })
+    );
+    assert_eq!(
+        nft.get("id"),
+        Some(&CadenceValue::UInt64 {
+            value: "7".to_string()
+        })
+    );
+    assert_eq!(nft.get(""), Some(&nft));
+    assert_eq!(nft.get("metadata/tags/2"), None);
+    assert_eq!(nft.get("metadata/tags/first"), None);
+    assert_eq!(nft.get("id/0"), None);
+    assert_eq!(nft.get("owner"), None);
+}
+
+#[test]
+fn equality_is_structural() {
+    let a: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Struct","value":{"id":"P","fields":[
+            {"name":"x","value":{"type":"Int","value":"1"}},
+            {"name":"y","value":{"type":"Int","value":"2"}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(a, a.clone());
+
+    // Field order and number spelling are part of the value
+    let reordered: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Struct","value":{"id":"P","fields":[
+            {"name":"y","value":{"type":"Int","value":"2"}},
+            {"name":"x","value":{"type":"Int","value":"1"}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_ne!(a, reordered);
+    assert_ne!(
+        CadenceValue::Int {
+            value: "1".to_string()
+        },
+        CadenceValue::Int {
+            value: "01".to_string()
+        }
+    );
+
+    assert_eq!(
+        CadenceType::parse("{String: Int}").unwrap(),
+        CadenceType::parse("{String:Int}").unwrap()
+    );
+    assert_ne!(CadenceType::Int, CadenceType::UInt);
+}
+
+#[test]
+fn canonical_sorts_and_normalizes() {
+    let value: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Struct","value":{"id":"P","fields":[
+            {"name":"y","value":{"type":"Int","value":"+007"}},
+            {"name":"x","value":{"type":"Dictionary","value":[
+                {"key":{"type":"String","value":"b"},"value":{"type":"UFix64","value":"1.5"}},
+                {"key":{"type":"String","value":"a"},"value":{"type":"Int","value":"-0"}}
+            ]}}
+        ]}}"#,
+    )
+    .unwrap();
+    let expected: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Struct","value":{"id":"P","fields":[
+            {"name":"x","value":{"type":"Dictionary","value":[
+                {"key":{"type":"String","value":"a"},"value":{"type":"Int","value":"0"}},
+                {"key":{"type":"String","value":"b"},"value":{"type":"UFix64","value":"1.50000000"}}
+            ]}},
+            {"name":"y","value":{"type":"Int","value":"7"}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(value.canonical(), expected);
+    assert_eq!(expected.canonical(), expected);
+
+    let mut in_place = value.clone();
+    in_place.canonicalize();
+    assert_eq!(in_place, expected);
+}
+
+#[test]
+fn canonical_values_deduplicate_in_a_hash_set() {
+    let a: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Dictionary","value":[
+            {"key":{"type":"String","value":"a"},"value":{"type":"UFix64","value":"1.5"}},
+            {"key":{"type":"String","value":"b"},"value":{"type":"Int","value":"2"}}
+        ]}"#,
+    )
+    .unwrap();
+    let b: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Dictionary","value":[
+            {"key":{"type":"String","value":"b"},"value":{"type":"Int","value":"2"}},
+            {"key":{"type":"String","value":"a"},"value":{"type":"UFix64","value":"1.50000000"}}
+        ]}"#,
+    )
+    .unwrap();
+    assert_ne!(a, b);
+    assert_eq!(Canonical::new(a.clone()), Canonical::new(b.clone()));
+
+    let other = CadenceValue::Bool { value: true };
+    let set: HashSet<Canonical> = [a, b, other.clone(), other]
+        .into_iter()
+        .map(Canonical::from)
+        .collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Canonical::new(CadenceValue::Bool { value: true })));
+}
+
+#[test]
+fn walk_visits_parents_before_children() {
+    let mut kinds = Vec::new();
+    nft().walk(&mut |value| {
+        kinds.push(match value {
+            CadenceValue::Resource { .. } => "Resource",
+            CadenceValue::Struct { .. } => "Struct",
+            CadenceValue::Array { .. } => "Array",
+            CadenceValue::String { .. } => "String",
+            CadenceValue::UInt64 { .. } => "UInt64",
+            _ => "other",
+        })
+    });
+    assert_eq!(
+        kinds,
+        ["Resource", "UInt64", "Struct", "Array", "String", "String"]
+    );
+}
+
+#[test]
+fn walk_mut_rewrites_nested_values() {
+    let mut nft = nft();
+    nft.walk_mut(&mut |value| {
+        if let CadenceValue::String { value } = value {
+            *value = value.to_uppercase();
+        }
+    });
+    assert_eq!(
+        nft.get("metadata/tags/0"),
+        Some(&CadenceValue::String {
+            value: "RARE".to_string()
+        })
+    );
+}
+
+#[test]
+fn composite_helpers_classify_values() {
+    let nft = nft();
+    assert!(nft.is_resource() && nft.is_composite());
+    assert!(!nft.is_struct() && !nft.is_event() && !nft.is_contract() && !nft.is_enum());
+    assert_eq!(nft.composite_id(), Some("NFT"));
+
+    let metadata = nft.get("metadata").unwrap();
+    assert!(metadata.is_struct());
+    assert_eq!(metadata.composite_id(), Some("Metadata"));
+
+    let tags = nft.get("metadata/tags").unwrap();
+    assert!(!tags.is_composite());
+    assert_eq!(tags.composite_id(), None);
+}
+
+#[test]
+fn integer_coercions_check_range_and_type() {
+    let word = CadenceValue::Word64 {
+        value: u64::MAX.to_string(),
+    };
+    assert_eq!(word.to_i128().unwrap(), i128::from(u64::MAX));
+    assert_eq!(word.to_u128().unwrap(), u128::from(u64::MAX));
+
+    let negative = CadenceValue::Int8 {
+        value: "-3".to_string(),
+    };
+    assert_eq!(negative.to_i128().unwrap(), -3);
+    assert!(matches!(negative.to_u128(), Err(Error::Custom(_))));
+
+    let huge = CadenceValue::UInt256 {
+        value: "1".repeat(60),
+    };
+    assert!(huge.to_i128().is_err());
+
+    let fixed = CadenceValue::UFix64 {
+        value: "1.00000000".to_string(),
+    };
+    assert!(matches!(fixed.to_i128(), Err(Error::TypeMismatch { .. })));
+}
+
+#[test]
+fn deep_size_counts_nested_allocations() {
+    let base = std::mem::size_of::<CadenceValue>();
+    assert_eq!(CadenceValue::Bool { value: true }.deep_size(), base);
+
+    let short = CadenceValue::String {
+        value: "ab".to_string(),
+    };
+    let long = CadenceValue::String {
+        value: "a".repeat(1000),
+    };
+    assert!(short.deep_size() >= base + 2);
+    assert!(long.deep_size() >= base + 1000);
+
+    let one = CadenceValue::Array {
+        value: vec![short.clone()],
+    };
+    let two = CadenceValue::Array {
+        value: vec![short.clone(), long.clone()],
+    };
+    assert!(one.deep_size() > short.deep_size());
+    assert!(two.deep_size() > one.deep_size() + 1000);
+
+    let nested = CadenceValue::Optional {
+        value: Some(Box::new(two.clone())),
+    };
+    assert!(nested.deep_size() > two.deep_size());
+    assert!(nft().deep_size() > base * 4);
+}
+
+#[test]
+fn merge_replaces_and_appends_fields() {
+    let mut nft = nft();
+    let update: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Resource","value":{"id":"NFT","fields":[
+            {"name":"id","value":{"type":"UInt64","value":"8"}},
+            {"name":"owner","value":{"type":"Address","value":"0x1"}}
+        ]}}"#,
+    )
+    .unwrap();
+    nft.merge(&update).unwrap();
+
+    let CadenceValue::Resource { value: composite } = &nft else {
+        panic!("expected a Resource, got {:?}", nft);
+    };
+    let fields: Vec<&str> = composite
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect();
+    assert_eq!(fields, vec!["id", "metadata", "owner"]);
+    assert_eq!(
+        nft.get("id"),
+        Some(&CadenceValue::UInt64 {
+            value: "8".to_string()
+        })
+    );
+    assert!(nft.get("metadata/tags/0").is_some());
+}
+
+#[test]
+fn merge_requires_the_same_composite_type() {
+    let mut nft = nft();
+    let other_id: CadenceValue =
+        serde_cadence::from_str(r#"{"type":"Resource","value":{"id":"Other","fields":[]}}"#)
+            .unwrap();
+    let other_kind: CadenceValue =
+        serde_cadence::from_str(r#"{"type":"Struct","value":{"id":"NFT","fields":[]}}"#).unwrap();
+    assert!(matches!(
+        nft.merge(&other_id),
+        Err(Error::TypeMismatch { .. })
+    ));
+    assert!(matches!(
+        nft.merge(&other_kind),
+        Err(Error::TypeMismatch { .. })
+    ));
+    assert!(
+        CadenceValue::Bool { value: true }
+            .merge(&CadenceValue::Bool { value: false })
+            .is_err()
+    );
+    assert_eq!(nft, self::nft());
+}
+
+#[test]
+fn flatten_optional_peels_every_layer() {
+    let inner = CadenceValue::Bool { value: true };
+    let wrapped = Some(Some(Some(true))).to_cadence_value().unwrap();
+    assert_eq!(wrapped.flatten_optional(), &inner);
+    assert_eq!(inner.flatten_optional(), &inner);
+
+    let nil = CadenceValue::Optional { value: None };
+    let nested_nil = Some(None::<bool>).to_cadence_value().unwrap();
+    assert_eq!(nested_nil.flatten_optional(), &nil);
+    assert_eq!(nil.flatten_optional(), &nil);
+}
+
+#[test]
+fn enum_helpers_build_and_inspect_cases() {
+    let color = CadenceValue::enum_case("A.0x1.Color", 2u8);
+    assert!(color.is_enum());
+    assert_eq!(color.enum_type_id(), Some("A.0x1.Color"));
+    assert_eq!(
+        color.enum_raw_value(),
+        Some(&2u8.to_cadence_value().unwrap())
+    );
+
+    let parsed: CadenceValue = serde_cadence::from_str(
+        r#"{"type":"Enum","value":{"id":"A.0x1.Color","fields":[
+            {"name":"rawValue","value":{"type":"UInt8","value":"2"}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(parsed, color);
+
+    // Other composites and values aren't enums
+    assert_eq!(nft().enum_type_id(), None);
+    assert_eq!(nft().enum_raw_value(), None);
+    assert_eq!(CadenceValue::Bool { value: true }.enum_raw_value(), None);
+}
+
+#[test]
+fn normalize_numbers_rewrites_payloads_in_canonical_form() {
+    let mut value = CadenceValue::Array {
+        value: vec![
+            CadenceValue::UInt8 {
+                value: "+007".to_string(),
+            },
+            CadenceValue::Int {
+                value: "-0".to_string(),
+            },
+            CadenceValue::UFix64 {
+                value: "1.5".to_string(),
+            },
+            CadenceValue::Fix64 {
+                value: "+1".to_string(),
+            },
+        ],
+    };
+    value.normalize_numbers();
+    let CadenceValue::Array { value: items } = &value else {
+        panic!("expected an array");
+    };
+    let payloads: Vec<_> = items
+        .iter()
+        .map(|item| match item {
+            CadenceValue::UInt8 { value }
+            | CadenceValue::Int { value }
+            | CadenceValue::UFix64 { value }
+            | CadenceValue::Fix64 { value } => value.as_str(),
+            other => panic!("unexpected {:?}", other),
+        })
+        .collect();
+    assert_eq!(payloads, ["7", "0", "1.50000000", "1.00000000"]);
+}
+
+#[test]
+fn fixed_point_payloads_need_digits_around_the_point() {
+    for malformed in ["1.", ".5", "1.123456789", "1.5x"] {
+        let mut value = CadenceValue::UFix64 {
+            value: malformed.to_string(),
+        };
+        value.normalize_numbers();
+        // Invalid payloads are left as they are
+        assert_eq!(
+            value,
+            CadenceValue::UFix64 {
+                value: malformed.to_string()
+            }
+        );
+        assert_eq!(value.as_fixed_point(), None, "{}", malformed);
+        assert!(malformed.parse::<UFix64>().is_err(), "{}", malformed);
+    }
+}
+
+#[test]
+fn pretty_cadence_renders_composites_like_constructors() {
+    assert_eq!(
+        nft().to_pretty_cadence(),
+        r#"NFT(id: 7, metadata: Metadata(tags: ["rare", "blue"]))"#
+    );
+    let empty = CadenceValue::Event {
+        value: CompositeValue {
+            id: "A.0x1.Market.Closed".into(),
+            fields: vec![],
+        },
+    };
+    assert_eq!(empty.to_pretty_cadence(), "A.0x1.Market.Closed()");
+}
+
+#[test]
+fn pretty_cadence_renders_dictionaries_in_entry_order() {
+    let scores =
+        serde_cadence::to_cadence_dictionary([("bob", Some(5u8)), ("alice", None)]).unwrap();
+    assert_eq!(scores.to_pretty_cadence(), r#"{"bob": 5, "alice": nil}"#);
+    let empty = CadenceValue::Dictionary { value: vec![] };
+    assert_eq!(empty.to_pretty_cadence(), "{}");
+}
+
+#[test]
+fn dictionary_get_finds_entries_by_key() {
+    let prices = serde_cadence::to_cadence_dictionary([("apple", 3u8), ("banana", 5)]).unwrap();
+    assert_eq!(prices.as_dictionary().map(<[_]>::len), Some(2));
+    assert_eq!(
+        prices.dictionary_get(&CadenceValue::from("banana")),
+        Some(&5u8.to_cadence_value().unwrap())
+    );
+    assert_eq!(prices.dictionary_get(&CadenceValue::from("cherry")), None);
+    assert_eq!(nft().dictionary_get(&CadenceValue::from("id")), None);
+    assert_eq!(nft().as_dictionary(), None);
+}
+
+#[test]
+fn dictionary_get_compares_keys_in_canonical_form() {
+    let ufix64 = |value: &str| CadenceValue::UFix64 {
+        value: value.to_string(),
+    };
+    let rates = CadenceValue::Dictionary {
+        value: vec![DictionaryEntry {
+            key: ufix64("1.00000000"),
+            value: CadenceValue::from("one"),
+        }],
+    };
+    assert_eq!(
+        rates.dictionary_get(&ufix64("1.0")),
+        Some(&CadenceValue::from("one"))
+    );
+    // The key type still has to match
+    let fix64 = CadenceValue::Fix64 {
+        value: "1.0".to_string(),
+    };
+    assert_eq!(rates.dictionary_get(&fix64), None);
+}
+
+fn person() -> CadenceValue {
+    serde_cadence::from_str(
+        r#"{"type":"Struct","value":{"id":"Person","fields":[
+            {"name":"name","value":{"type":"String","value":"Alice"}},
+            {"name":"age","value":{"type":"UInt8","value":"30"}},
+            {"name":"isActive","value":{"type":"Bool","value":true}}
+        ]}}"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn take_field_moves_a_field_out_of_a_composite() {
+    let mut person = person();
+    let age = person.take_field("age").unwrap();
+    assert_eq!(age.name, "age");
+    assert_eq!(age.value, 30u8.to_cadence_value().unwrap());
+
+    // The remaining fields keep their order
+    let CadenceValue::Struct { value: composite } = &person else {
+        panic!("expected a struct, got {:?}", person);
+    };
+    let names: Vec<&str> = composite.fields.iter().map(|field| &*field.name).collect();
+    assert_eq!(names, ["name", "isActive"]);
+    assert!(person.get("age").is_none());
+}
+
+#[test]
+fn take_field_returns_none_when_there_is_nothing_to_take() {
+    let mut person = person();
+    assert!(person.take_field("email").is_none());
+    assert_eq!(person, self::person());
+
+    let mut tags = CadenceValue::Array {
+        value: vec![CadenceValue::from("age")],
+    };
+    assert!(tags.take_field("age").is_none());
+}
+
+#[test]
+fn capabilities_build_and_read_back() {
+    let borrow_type = CadenceType::reference(CadenceType::Int);
+    let capability = CadenceValue::capability("1", "0x0000000000000001", borrow_type.clone());
+    assert_eq!(
+        capability.as_capability(),
+        Some(("1", "0x0000000000000001", &borrow_type))
+    );
+
+    // Flow's encoding of the same capability
+    let flow_json = include_str!("../examples/golden/values/capability.json");
+    assert_eq!(
+        serde_json::to_string(&capability).unwrap(),
+        flow_json.trim_end()
+    );
+    let parsed: CadenceValue = serde_json::from_str(flow_json).unwrap();
+    assert_eq!(parsed, capability);
+
+    assert_eq!(nft().as_capability(), None);
+}
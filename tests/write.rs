@@ -0,0 +1,55 @@
+// tests/write.rs
+//
+// Writing Cadence-JSON with to_writer, to_writer_pretty and encode_arguments
+
+#![cfg(feature = "std")]
+
+#[test]
+fn to_writer_matches_to_string() {
+    let mut buffer = Vec::new();
+    serde_cadence::to_writer(&mut buffer, &vec![1u8, 2]).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        serde_cadence::to_string(&vec![1u8, 2]).unwrap()
+    );
+
+    let mut buffer = Vec::new();
+    serde_cadence::to_writer_pretty(&mut buffer, &true).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "{\n  \"type\": \"Bool\",\n  \"value\": true\n}"
+    );
+}
+
+#[test]
+fn encode_arguments_writes_one_json_string_per_argument() {
+    let args: [&dyn serde_cadence::ToCadenceValue; 3] = [&42u64, &"hello", &true];
+    let encoded = serde_cadence::encode_arguments(&args).unwrap();
+    assert_eq!(
+        encoded,
+        [
+            r#"{"type":"UInt64","value":"42"}"#,
+            r#"{"type":"String","value":"hello"}"#,
+            r#"{"type":"Bool","value":true}"#,
+        ]
+    );
+    // Each argument parses back on its own
+    let id: u64 = serde_cadence::from_str(&encoded[0]).unwrap();
+    assert_eq!(id, 42);
+    assert!(serde_cadence::encode_arguments(&[]).unwrap().is_empty());
+}
+
+struct Unencodable;
+
+impl serde_cadence::ToCadenceValue for Unencodable {
+    fn to_cadence_value(&self) -> serde_cadence::Result<serde_cadence::CadenceValue> {
+        Err(serde_cadence::Error::Custom("not encodable".to_string()))
+    }
+}
+
+#[test]
+fn encode_arguments_reports_the_failing_position() {
+    let args: [&dyn serde_cadence::ToCadenceValue; 2] = [&1u8, &Unencodable];
+    let error = serde_cadence::encode_arguments(&args).unwrap_err();
+    assert_eq!(error.to_string(), "at [1]: not encodable");
+}